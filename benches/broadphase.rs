@@ -0,0 +1,121 @@
+//! Broadphase stress test: 10k falling objects against 4 players.
+//!
+//!     cargo bench --bench broadphase
+//!
+//! Every tick moves the objects, rebuilds the grid and queries each player,
+//! the same work `rebuild_broadphase` + `detect_collisions` do in the game.
+//! Results are cross-checked against a brute-force pass, and the run fails
+//! if the mean grid tick exceeds the 1 ms budget.
+
+use std::time::{Duration, Instant};
+
+use bevy::math::Vec2;
+use christmas_overclock::collision::{ColliderShape, Hitbox, UniformGrid};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const OBJECTS: usize = 10_000;
+const PLAYERS: usize = 4;
+const TICKS: usize = 600;
+const BUDGET: Duration = Duration::from_millis(1);
+
+struct Falling {
+    hitbox: Hitbox,
+    speed: f32,
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(2512);
+
+    // Present / fan / bit sized circles, like the spawners make
+    let radii = [17.0, 18.6, 12.7];
+    let mut objects: Vec<Falling> = (0..OBJECTS)
+        .map(|i| {
+            let r: f32 = radii[i % radii.len()];
+            Falling {
+                hitbox: Hitbox {
+                    center: Vec2::new(rng.gen_range(-300.0..300.0), rng.gen_range(-360.0..300.0)),
+                    half: Vec2::splat(r),
+                    shape: ColliderShape::Circle,
+                },
+                speed: rng.gen_range(2.0..9.0),
+            }
+        })
+        .collect();
+
+    let mut players: Vec<Hitbox> = (0..PLAYERS)
+        .map(|i| Hitbox {
+            center: Vec2::new(-240.0 + 160.0 * i as f32, -250.0),
+            half: Vec2::new(25.0, 32.0),
+            shape: ColliderShape::Aabb,
+        })
+        .collect();
+
+    let mut grid = UniformGrid::new(Vec2::splat(-400.0), Vec2::splat(400.0), 64.0);
+    let mut grid_ticks = Vec::with_capacity(TICKS);
+    let mut brute_ticks = Vec::with_capacity(TICKS);
+    let mut hits = 0usize;
+
+    for tick in 0..TICKS {
+        for object in &mut objects {
+            object.hitbox.center.y -= object.speed;
+            if object.hitbox.center.y < -360.0 {
+                object.hitbox.center.y += 660.0;
+            }
+        }
+        for (i, player) in players.iter_mut().enumerate() {
+            player.center.x = (tick as f32 * 0.05 + i as f32).sin() * 300.0;
+        }
+
+        // Grid
+        let start = Instant::now();
+        grid.rebuild(objects.iter().enumerate().map(|(i, o)| (o.hitbox, i)));
+        let mut grid_hits: Vec<(usize, usize)> = Vec::new();
+        for (p, player) in players.iter().enumerate() {
+            grid_hits.extend(grid.overlapping(player).map(|i| (p, *i)));
+        }
+        grid_ticks.push(start.elapsed());
+
+        // Brute force, for comparison and as the reference answer
+        let start = Instant::now();
+        let mut brute_hits: Vec<(usize, usize)> = Vec::new();
+        for (p, player) in players.iter().enumerate() {
+            for (i, object) in objects.iter().enumerate() {
+                if object.hitbox.overlaps(player) {
+                    brute_hits.push((p, i));
+                }
+            }
+        }
+        brute_ticks.push(start.elapsed());
+
+        grid_hits.sort_unstable();
+        assert_eq!(
+            grid_hits, brute_hits,
+            "grid disagrees with brute force on tick {tick}"
+        );
+        hits += grid_hits.len();
+    }
+
+    let grid_mean = report("grid", &mut grid_ticks);
+    report("brute force", &mut brute_ticks);
+    println!("{OBJECTS} objects x {PLAYERS} players, {TICKS} ticks, {hits} overlaps (verified)");
+
+    if grid_mean > BUDGET {
+        eprintln!("❌ mean grid tick {grid_mean:?} is over the {BUDGET:?} budget");
+        std::process::exit(1);
+    }
+}
+
+fn report(name: &str, ticks: &mut [Duration]) -> Duration {
+    ticks.sort_unstable();
+    let mean = ticks.iter().sum::<Duration>() / ticks.len() as u32;
+    let pct = |p: f32| ticks[((ticks.len() - 1) as f32 * p) as usize];
+
+    println!(
+        "{name:>12}: mean {mean:>10.2?}  p50 {:>10.2?}  p99 {:>10.2?}  max {:>10.2?}",
+        pct(0.5),
+        pct(0.99),
+        ticks[ticks.len() - 1]
+    );
+    mean
+}
@@ -0,0 +1,68 @@
+//! Gym throughput: headless episodes stepped through `game::gym::Env`.
+//!
+//!     cargo bench --bench gym
+//!
+//! Plays a few episodes per policy (random discrete actions, and the expert
+//! bot the way an expert replay would be recorded) and fails if the
+//! environment steps slower than the 2000 steps/s training needs.
+
+use std::time::{Duration, Instant};
+
+use christmas_overclock::game::bot::{Bot, BotProfile};
+use christmas_overclock::game::gym::{Action, Env, Observation};
+use christmas_overclock::game::sim::FRAME;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const EPISODES: u64 = 4;
+const MAX_SECONDS: f32 = 60.0;
+const BUDGET: f64 = 2000.0; // steps per second
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(2512);
+    let random = play("random", |_, _| {
+        Action::from_index(rng.gen_range(0..Action::COUNT))
+    });
+
+    let mut bot = Bot::new(BotProfile::EXPERT);
+    let expert = play("expert bot", |env, _| {
+        Action::from(bot.act(env.snapshot().expect("reset"), FRAME))
+    });
+
+    let slowest = random.min(expert);
+    if slowest < BUDGET {
+        eprintln!("❌ {slowest:.0} steps/s is under the {BUDGET:.0} steps/s budget");
+        std::process::exit(1);
+    }
+}
+
+// Steps/s over all episodes, with one line of stats
+fn play(name: &str, mut policy: impl FnMut(&Env, &Observation) -> Action) -> f64 {
+    let mut env = Env::new().with_max_seconds(MAX_SECONDS);
+    let mut steps = 0u64;
+    let mut reward = 0.0;
+    let mut elapsed = Duration::ZERO;
+
+    for seed in 0..EPISODES {
+        let mut observation = env.reset(seed);
+        let start = Instant::now();
+        loop {
+            let action = policy(&env, &observation);
+            let (next, r, done, _) = env.step(action);
+            steps += 1;
+            reward += r;
+            if done {
+                break;
+            }
+            observation = next;
+        }
+        elapsed += start.elapsed();
+    }
+
+    let rate = steps as f64 / elapsed.as_secs_f64();
+    println!(
+        "{name:>12}: {steps:>6} steps  {rate:>8.0} steps/s  mean return {:>7.1}",
+        reward / EPISODES as f32
+    );
+    rate
+}
@@ -0,0 +1,63 @@
+//! Append-only run exports for offline analysis: JSON lines and CSV.
+//!
+//! Files live under `analytics/` next to the game. Both writers append, so a
+//! session's runs accumulate and can be loaded straight into a notebook. The
+//! web build has no file system and reports an error instead.
+
+use serde::Serialize;
+
+#[cfg(not(target_arch = "wasm32"))]
+const EXPORT_DIR: &str = "analytics";
+
+/// Appends `record` as one JSON object per line.
+pub fn append_json_line<T: Serialize>(file: &str, record: &T) -> Result<(), String> {
+    let mut line = serde_json::to_string(record).map_err(|err| err.to_string())?;
+    line.push('\n');
+    append(file, line.as_bytes())
+}
+
+/// Appends `row` to a CSV file, writing the header first if the file is new.
+/// `row` must be flat (no nested structs or sequences).
+pub fn append_csv_row<T: Serialize>(file: &str, row: &T) -> Result<(), String> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(!exists(file))
+        .from_writer(Vec::new());
+    writer.serialize(row).map_err(|err| err.to_string())?;
+    let bytes = writer.into_inner().map_err(|err| err.to_string())?;
+    append(file, &bytes)
+}
+
+/// FNV-1a: a stable 64-bit hash, unlike `DefaultHasher` across Rust releases.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn exists(file: &str) -> bool {
+    std::path::Path::new(EXPORT_DIR).join(file).exists()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn exists(_file: &str) -> bool {
+    false
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn append(file: &str, bytes: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(EXPORT_DIR).map_err(|err| err.to_string())?;
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(std::path::Path::new(EXPORT_DIR).join(file))
+        .and_then(|mut out| out.write_all(bytes))
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn append(_file: &str, _bytes: &[u8]) -> Result<(), String> {
+    Err("run export needs a native build".to_string())
+}
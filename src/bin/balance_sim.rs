@@ -0,0 +1,291 @@
+//! Balance check: many headless runs of one bot, summarised.
+//!
+//!     cargo run --release --bin balance-sim -- --runs 200 --bot expert \
+//!         --config assets/config.ron --bounds balance/bounds.ron
+//!
+//! Seeds `--seed`..`--seed + --runs` are played in parallel until Santa
+//! crashes or `--max-seconds` pass. Prints survival / score percentiles,
+//! crash cause ratios and time to reach difficulty 2.0 and 3.2, then checks
+//! them against the bounds file (if given) and exits with 1 on a violation.
+//! The same seeds and config always give the same report.
+
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use christmas_overclock::game::bot::BotProfile;
+use christmas_overclock::game::sim::Simulation;
+use christmas_overclock::game::CrashCause;
+use serde::Deserialize;
+
+const USAGE: &str =
+    "usage: balance-sim [--runs N] [--seed FIRST] [--bot novice|casual|expert|FILE.ron]
+                   [--config FILE.ron] [--bounds FILE.ron] [--threads N] [--max-seconds S]";
+
+// Difficulty levels worth knowing the arrival time of
+const MILESTONES: [(f32, &str); 2] = [(2.0, "difficulty_2"), (3.2, "difficulty_3_2")];
+const PERCENTILES: [u32; 5] = [10, 25, 50, 75, 90];
+
+struct Options {
+    runs: u64,
+    first_seed: u64,
+    bot_name: String,
+    bot: BotProfile,
+    config_path: String,
+    config: String,
+    bounds: Option<(String, BTreeMap<String, Bound>)>,
+    threads: usize,
+    max_seconds: f32,
+}
+
+/// Allowed range for one metric; either side may be left open.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Bound {
+    min: Option<f32>,
+    max: Option<f32>,
+}
+
+struct Run {
+    seconds: f32,
+    score: u32,
+    cause: Option<CrashCause>,
+    // Seconds alive when each milestone was reached
+    milestones: [Option<f32>; MILESTONES.len()],
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("balance-sim: {err}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let started = Instant::now();
+    let runs = match play(&options) {
+        Ok(runs) => runs,
+        Err(err) => {
+            eprintln!("balance-sim: {}: {err}", options.config_path);
+            return ExitCode::from(2);
+        }
+    };
+
+    println!(
+        "🎄 {} runs (seeds {}..{}), bot {}, config {}, {} threads, {:.1} s",
+        runs.len(),
+        options.first_seed,
+        options.first_seed + options.runs,
+        options.bot_name,
+        options.config_path,
+        options.threads,
+        started.elapsed().as_secs_f32()
+    );
+    let metrics = metrics(&runs);
+    for (name, value) in &metrics {
+        println!("{name:<24} {}", format_metric(*value));
+    }
+
+    let Some((path, bounds)) = &options.bounds else {
+        return ExitCode::SUCCESS;
+    };
+    let violations = check_bounds(&metrics, bounds);
+    if violations.is_empty() {
+        println!("✅ all {} bounds in {path} hold", bounds.len());
+        return ExitCode::SUCCESS;
+    }
+    for violation in &violations {
+        println!("❌ {violation}");
+    }
+    println!(
+        "{} of {} bounds in {path} violated",
+        violations.len(),
+        bounds.len()
+    );
+    ExitCode::FAILURE
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        runs: 100,
+        first_seed: 0,
+        bot_name: "casual".to_string(),
+        bot: BotProfile::CASUAL,
+        config_path: "assets/config.ron".to_string(),
+        config: String::new(),
+        bounds: None,
+        threads: std::thread::available_parallelism().map_or(4, |n| n.get()),
+        max_seconds: 600.0,
+    };
+    let read = |path: &str| std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"));
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+        let number = |what: &str| format!("{flag}: {value:?} is not {what}");
+
+        match flag.as_str() {
+            "--runs" => options.runs = value.parse().map_err(|_| number("a count"))?,
+            "--seed" => options.first_seed = value.parse().map_err(|_| number("a seed"))?,
+            "--threads" => {
+                options.threads = value.parse().map_err(|_| number("a count"))?;
+            }
+            "--max-seconds" => {
+                options.max_seconds = value.parse().map_err(|_| number("a number"))?;
+            }
+            "--bot" => {
+                options.bot = match BotProfile::preset(&value) {
+                    Some(profile) => profile,
+                    None => {
+                        ron::from_str(&read(&value)?).map_err(|err| format!("{value}: {err}"))?
+                    }
+                };
+                options.bot_name = value;
+            }
+            "--config" => options.config_path = value,
+            "--bounds" => {
+                let bounds =
+                    ron::from_str(&read(&value)?).map_err(|err| format!("{value}: {err}"))?;
+                options.bounds = Some((value, bounds));
+            }
+            _ => return Err(format!("unknown option {flag}")),
+        }
+    }
+
+    options.config = read(&options.config_path)?;
+    options.threads = options.threads.max(1);
+    Ok(options)
+}
+
+// 🧵 Each thread pulls the next seed until the range is used up
+fn play(options: &Options) -> Result<Vec<Run>, String> {
+    let next = AtomicU64::new(0);
+    let results = Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..options.threads {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= options.runs {
+                    break;
+                }
+                let run = play_one(options, options.first_seed + index);
+                let failed = run.is_err();
+                results.lock().unwrap().push((index, run));
+                if failed {
+                    break;
+                }
+            });
+        }
+    });
+
+    // Seed order, so the report doesn't depend on thread timing
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, run)| run).collect()
+}
+
+fn play_one(options: &Options, seed: u64) -> Result<Run, String> {
+    let mut sim = Simulation::with_config(seed, &options.config)?;
+    sim.set_bot(options.bot);
+
+    let mut milestones = [None; MILESTONES.len()];
+    while !sim.is_crashed() && sim.snapshot().time_alive < options.max_seconds {
+        sim.step();
+
+        let snapshot = sim.snapshot();
+        for (reached, (level, _)) in milestones.iter_mut().zip(MILESTONES) {
+            if reached.is_none() && snapshot.difficulty >= level {
+                *reached = Some(snapshot.time_alive);
+            }
+        }
+    }
+
+    let snapshot = sim.snapshot();
+    Ok(Run {
+        seconds: snapshot.time_alive,
+        score: snapshot.score,
+        cause: sim.crash_cause(),
+        milestones,
+    })
+}
+
+// Metric name → value; NaN when no run got far enough to measure it
+fn metrics(runs: &[Run]) -> Vec<(String, f32)> {
+    let mut metrics = Vec::new();
+    let share = |count: usize| count as f32 / runs.len().max(1) as f32;
+
+    let mut percentiles = |name: &str, mut values: Vec<f32>| {
+        values.sort_by(f32::total_cmp);
+        for p in PERCENTILES {
+            metrics.push((format!("{name}.p{p}"), percentile(&values, p)));
+        }
+    };
+    percentiles("survival", runs.iter().map(|run| run.seconds).collect());
+    percentiles("score", runs.iter().map(|run| run.score as f32).collect());
+    for (i, (_, name)) in MILESTONES.iter().enumerate() {
+        percentiles(
+            name,
+            runs.iter().filter_map(|run| run.milestones[i]).collect(),
+        );
+    }
+
+    for (i, (_, name)) in MILESTONES.iter().enumerate() {
+        let reached = runs
+            .iter()
+            .filter(|run| run.milestones[i].is_some())
+            .count();
+        metrics.push((format!("{name}.reached"), share(reached)));
+    }
+    for (cause, name) in [
+        (Some(CrashCause::Overheat), "crash.overheat"),
+        (Some(CrashCause::Corrupted), "crash.corrupted"),
+        (Some(CrashCause::DeliveryFailed), "crash.delivery_failed"),
+        (None, "survived"),
+    ] {
+        let count = runs.iter().filter(|run| run.cause == cause).count();
+        metrics.push((name.to_string(), share(count)));
+    }
+    metrics
+}
+
+// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[f32], p: u32) -> f32 {
+    if sorted.is_empty() {
+        return f32::NAN;
+    }
+    let rank = (p as f32 / 100.0 * sorted.len() as f32).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn format_metric(value: f32) -> String {
+    if value.is_nan() {
+        "-".to_string()
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn check_bounds(metrics: &[(String, f32)], bounds: &BTreeMap<String, Bound>) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    for (name, bound) in bounds {
+        let Some((_, value)) = metrics.iter().find(|(metric, _)| metric == name) else {
+            violations.push(format!("{name}: no such metric"));
+            continue;
+        };
+        if value.is_nan() {
+            violations.push(format!("{name}: no run got far enough to measure it"));
+            continue;
+        }
+        if let Some(min) = bound.min.filter(|min| value < min) {
+            violations.push(format!("{name} = {value:.2}, below {min}"));
+        }
+        if let Some(max) = bound.max.filter(|max| value > max) {
+            violations.push(format!("{name} = {value:.2}, above {max}"));
+        }
+    }
+    violations
+}
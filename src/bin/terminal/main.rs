@@ -0,0 +1,19 @@
+//! Terminal frontend: the headless simulation drawn with characters.
+//!
+//!     cargo run --release --bin terminal -- [--seed N] [--bot expert]
+//!     cargo run --release --bin terminal -- --dump 600 --size 80x24 > frame.txt
+//!
+//! Plays the same game as the window build, so it works over SSH. `--dump`
+//! skips the terminal and prints the text frame after that many frames.
+//! Native only; the web build has no terminal to draw in.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod play;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> std::process::ExitCode {
+    play::main()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
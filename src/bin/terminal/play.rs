@@ -0,0 +1,283 @@
+use std::io;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use bevy::math::Vec2;
+use christmas_overclock::game::bot::BotProfile;
+use christmas_overclock::game::sim::{Simulation, Snapshot, FRAME};
+use christmas_overclock::game::text::TextFrame;
+use christmas_overclock::game::Actions;
+use ratatui::crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, KeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use ratatui::crossterm::{execute, terminal};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::DefaultTerminal;
+
+const USAGE: &str = "usage: terminal [--seed N] [--bot novice|casual|expert|FILE.ron]
+                [--config FILE.ron] [--dump FRAMES] [--size COLSxROWS]";
+
+struct Options {
+    seed: u64,
+    bot: Option<BotProfile>,
+    config: Option<(String, String)>,
+    dump: Option<u64>,
+    size: (usize, usize),
+}
+
+pub fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("terminal: {err}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut sim = match &options.config {
+        Some((path, config)) => match Simulation::with_config(options.seed, config) {
+            Ok(sim) => sim,
+            Err(err) => {
+                eprintln!("terminal: {path}: {err}");
+                return ExitCode::from(2);
+            }
+        },
+        None => Simulation::new(options.seed),
+    };
+    if let Some(profile) = options.bot {
+        sim.set_bot(profile);
+    }
+
+    // 📄 No terminal: play blind and print one frame
+    if let Some(frames) = options.dump {
+        for _ in 0..frames {
+            sim.step();
+        }
+        let (width, height) = options.size;
+        print!("{}", TextFrame::render(sim.snapshot(), width, height));
+        return ExitCode::SUCCESS;
+    }
+
+    match run(&mut sim, options.seed) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("terminal: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        seed: rand::random(),
+        bot: None,
+        config: None,
+        dump: None,
+        size: (80, 24),
+    };
+    let read = |path: &str| std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"));
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+        let number = |what: &str| format!("{flag}: {value:?} is not {what}");
+
+        match flag.as_str() {
+            "--seed" => options.seed = value.parse().map_err(|_| number("a seed"))?,
+            "--dump" => options.dump = Some(value.parse().map_err(|_| number("a count"))?),
+            "--size" => {
+                let (columns, rows) = value
+                    .split_once('x')
+                    .and_then(|(columns, rows)| Some((columns.parse().ok()?, rows.parse().ok()?)))
+                    .ok_or_else(|| number("COLSxROWS"))?;
+                options.size = (columns, rows);
+            }
+            "--bot" => {
+                options.bot = Some(match BotProfile::preset(&value) {
+                    Some(profile) => profile,
+                    None => {
+                        ron::from_str(&read(&value)?).map_err(|err| format!("{value}: {err}"))?
+                    }
+                });
+            }
+            "--config" => options.config = Some((value.clone(), read(&value)?)),
+            _ => return Err(format!("unknown option {flag}")),
+        }
+    }
+    Ok(options)
+}
+
+// ⌨️ Most terminals only report key presses. Where release events exist
+// (kitty keyboard protocol) keys are held like in the window build;
+// elsewhere ← → latch a direction (↓ stops) and SPACE toggles overclock.
+#[derive(Default)]
+struct Controls {
+    releases: bool,
+    left: bool,
+    right: bool,
+    overclock: bool,
+    dash: bool,
+}
+
+impl Controls {
+    fn key(&mut self, key: KeyEvent) {
+        let pressed = key.kind != KeyEventKind::Release;
+        let fresh = key.kind == KeyEventKind::Press;
+
+        match key.code {
+            KeyCode::Left | KeyCode::Char('a') => {
+                self.left = pressed;
+                if !self.releases {
+                    self.right = false;
+                }
+            }
+            KeyCode::Right | KeyCode::Char('d') => {
+                self.right = pressed;
+                if !self.releases {
+                    self.left = false;
+                }
+            }
+            KeyCode::Down | KeyCode::Char('s') if fresh => {
+                self.left = false;
+                self.right = false;
+            }
+            KeyCode::Char(' ') if self.releases => self.overclock = pressed,
+            KeyCode::Char(' ') if fresh => self.overclock = !self.overclock,
+            KeyCode::Char('x') if fresh => self.dash = true,
+            _ => {}
+        }
+        if key.modifiers.contains(KeyModifiers::SHIFT) && fresh {
+            self.dash = true;
+        }
+    }
+
+    // This frame's input; a dash is a single press
+    fn take(&mut self) -> Actions {
+        let movement = f32::from(self.right) - f32::from(self.left);
+        Actions {
+            movement: Vec2::new(movement, 0.0),
+            overclock: self.overclock,
+            dash: std::mem::take(&mut self.dash),
+        }
+    }
+}
+
+fn run(sim: &mut Simulation, seed: u64) -> io::Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    let releases = terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if releases {
+        execute!(
+            io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+    }
+
+    let result = play(&mut terminal, sim, seed, releases);
+
+    if releases {
+        execute!(io::stdout(), PopKeyboardEnhancementFlags)?;
+    }
+    ratatui::restore();
+    result
+}
+
+fn play(
+    terminal: &mut DefaultTerminal,
+    sim: &mut Simulation,
+    seed: u64,
+    releases: bool,
+) -> io::Result<()> {
+    let frame_time = Duration::from_secs_f32(FRAME);
+    let mut controls = Controls {
+        releases,
+        ..Default::default()
+    };
+    let mut next_frame = Instant::now();
+
+    loop {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let text = TextFrame::render(
+                sim.snapshot(),
+                usize::from(area.width),
+                usize::from(area.height).saturating_sub(1),
+            );
+            let mut lines = colour(&text, sim.snapshot());
+            lines.push(Line::styled(
+                format!("seed {seed}  ← → move  SPACE overclock  X dash  R reboot  Q quit"),
+                Style::new().fg(Color::DarkGray),
+            ));
+            frame.render_widget(Paragraph::new(lines), area);
+        })?;
+
+        // Handle input until the next frame is due
+        while let Some(wait) = next_frame.checked_duration_since(Instant::now()) {
+            if !event::poll(wait)? {
+                break;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            let ctrl_c =
+                key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                _ if ctrl_c => return Ok(()),
+                KeyCode::Char('r') if key.kind == KeyEventKind::Press => sim.restart(),
+                _ => controls.key(key),
+            }
+        }
+
+        sim.set_actions(controls.take());
+        sim.step();
+
+        // Fell behind (slow terminal, suspended process): don't race to catch up
+        next_frame += frame_time;
+        let now = Instant::now();
+        if now > next_frame + frame_time * 15 {
+            next_frame = now;
+        }
+    }
+}
+
+// Colours by glyph; the heat bar follows the HUD's heat colours
+fn colour(text: &TextFrame, snapshot: &Snapshot) -> Vec<Line<'static>> {
+    let heat = if snapshot.heat > 70.0 {
+        Color::LightRed
+    } else {
+        Color::Red
+    };
+
+    let banner = text.height() / 2 - 1..=text.height() / 2 + 1;
+
+    text.lines()
+        .enumerate()
+        .map(|(row, line)| {
+            if row == 0 {
+                return Line::styled(line, Style::new().fg(heat));
+            }
+            if snapshot.crashed && banner.contains(&row) {
+                return Line::styled(line, Style::new().fg(Color::White));
+            }
+            let spans: Vec<Span> = line
+                .chars()
+                .map(|glyph| {
+                    let color = match glyph {
+                        '<' | 'S' | '>' => Color::Red,
+                        '#' => Color::Green,
+                        '$' => Color::Yellow,
+                        '@' => Color::Cyan,
+                        '%' | 'G' => Color::LightGreen,
+                        'c' => Color::DarkGray,
+                        'e' => Color::Magenta,
+                        _ => Color::White,
+                    };
+                    Span::styled(glyph.to_string(), Style::new().fg(color))
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
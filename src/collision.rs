@@ -0,0 +1,250 @@
+//! Collision geometry and the uniform-grid broadphase.
+//!
+//! Kept free of ECS types so benches and tools can drive it directly.
+
+use bevy::math::Vec2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColliderShape {
+    Aabb,
+    Circle,
+}
+
+/// World-space hitbox for one tick. Circles use the smaller half extent.
+#[derive(Clone, Copy, Debug)]
+pub struct Hitbox {
+    pub center: Vec2,
+    pub half: Vec2,
+    pub shape: ColliderShape,
+}
+
+impl Hitbox {
+    pub fn radius(&self) -> f32 {
+        self.half.min_element()
+    }
+
+    pub fn overlaps(&self, other: &Hitbox) -> bool {
+        match (self.shape, other.shape) {
+            (ColliderShape::Aabb, ColliderShape::Aabb) => {
+                let d = (self.center - other.center).abs();
+                d.x < self.half.x + other.half.x && d.y < self.half.y + other.half.y
+            }
+            (ColliderShape::Circle, ColliderShape::Circle) => {
+                self.center.distance(other.center) < self.radius() + other.radius()
+            }
+            (ColliderShape::Aabb, ColliderShape::Circle) => {
+                aabb_circle(self.center, self.half, other.center, other.radius())
+            }
+            (ColliderShape::Circle, ColliderShape::Aabb) => {
+                aabb_circle(other.center, other.half, self.center, self.radius())
+            }
+        }
+    }
+}
+
+fn aabb_circle(center: Vec2, half: Vec2, circle: Vec2, radius: f32) -> bool {
+    let closest = circle.clamp(center - half, center + half);
+    closest.distance_squared(circle) < radius * radius
+}
+
+/// Loose uniform grid over a fixed world rectangle.
+///
+/// Each entry lives in the one cell holding its center; queries widen by the
+/// largest half extent seen during the last rebuild, so nothing is missed and
+/// nothing is reported twice. Entries outside the rectangle are clamped into
+/// the edge cells. Storage is a counting sort into one flat array, so a
+/// rebuild allocates nothing once the buffers have grown.
+pub struct UniformGrid<T> {
+    min: Vec2,
+    inv_cell_size: f32,
+    cols: usize,
+    rows: usize,
+    starts: Vec<u32>,
+    cursor: Vec<u32>,
+    binned: Vec<(u32, Hitbox, T)>,
+    items: Vec<(Hitbox, T)>,
+    max_half: Vec2,
+}
+
+impl<T: Copy> UniformGrid<T> {
+    pub fn new(min: Vec2, max: Vec2, cell_size: f32) -> Self {
+        let cols = (((max.x - min.x) / cell_size).ceil() as usize).max(1);
+        let rows = (((max.y - min.y) / cell_size).ceil() as usize).max(1);
+
+        Self {
+            min,
+            inv_cell_size: 1.0 / cell_size,
+            cols,
+            rows,
+            starts: vec![0; cols * rows + 1],
+            cursor: vec![0; cols * rows],
+            binned: Vec::new(),
+            items: Vec::new(),
+            max_half: Vec2::ZERO,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn rebuild(&mut self, entries: impl IntoIterator<Item = (Hitbox, T)>) {
+        self.binned.clear();
+        self.max_half = Vec2::ZERO;
+
+        for (hitbox, value) in entries {
+            let (col, row) = self.cell(hitbox.center);
+            self.max_half = self.max_half.max(hitbox.half);
+            self.binned
+                .push(((row * self.cols + col) as u32, hitbox, value));
+        }
+
+        // Counting sort by cell
+        self.starts.fill(0);
+        for (cell, _, _) in &self.binned {
+            self.starts[*cell as usize + 1] += 1;
+        }
+        for i in 1..self.starts.len() {
+            self.starts[i] += self.starts[i - 1];
+        }
+        self.cursor
+            .copy_from_slice(&self.starts[..self.starts.len() - 1]);
+
+        self.items.clear();
+        self.items.extend(
+            self.binned
+                .iter()
+                .map(|(_, hitbox, value)| (*hitbox, *value)),
+        );
+        for (cell, hitbox, value) in &self.binned {
+            let slot = &mut self.cursor[*cell as usize];
+            self.items[*slot as usize] = (*hitbox, *value);
+            *slot += 1;
+        }
+    }
+
+    /// Every entry whose hitbox overlaps `area`.
+    pub fn overlapping<'a>(&'a self, area: &'a Hitbox) -> impl Iterator<Item = &'a T> + 'a {
+        let reach = area.half + self.max_half;
+        let (c0, r0) = self.cell(area.center - reach);
+        let (c1, r1) = self.cell(area.center + reach);
+
+        (r0..=r1)
+            .flat_map(move |row| {
+                let lo = self.starts[row * self.cols + c0] as usize;
+                let hi = self.starts[row * self.cols + c1 + 1] as usize;
+                self.items[lo..hi].iter()
+            })
+            .filter(move |(hitbox, _)| hitbox.overlaps(area))
+            .map(|(_, value)| value)
+    }
+
+    fn cell(&self, p: Vec2) -> (usize, usize) {
+        // Truncation == floor once clamped to >= 0 (and skips a libm call)
+        let local = (p - self.min) * self.inv_cell_size;
+        let col = (local.x.max(0.0) as usize).min(self.cols - 1);
+        let row = (local.y.max(0.0) as usize).min(self.rows - 1);
+        (col, row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle(x: f32, y: f32, r: f32) -> Hitbox {
+        Hitbox {
+            center: Vec2::new(x, y),
+            half: Vec2::splat(r),
+            shape: ColliderShape::Circle,
+        }
+    }
+
+    fn aabb(x: f32, y: f32, hx: f32, hy: f32) -> Hitbox {
+        Hitbox {
+            center: Vec2::new(x, y),
+            half: Vec2::new(hx, hy),
+            shape: ColliderShape::Aabb,
+        }
+    }
+
+    // 4×4 cells of 50 over [-100, 100]²
+    fn grid(entries: &[Hitbox]) -> UniformGrid<usize> {
+        let mut grid = UniformGrid::new(Vec2::splat(-100.0), Vec2::splat(100.0), 50.0);
+        grid.rebuild(entries.iter().copied().zip(0..));
+        grid
+    }
+
+    fn hits(grid: &UniformGrid<usize>, area: Hitbox) -> Vec<usize> {
+        let mut hits: Vec<usize> = grid.overlapping(&area).copied().collect();
+        hits.sort_unstable();
+        hits
+    }
+
+    #[test]
+    fn finds_entries_straddling_cells() {
+        // Centered just left of x = 0, reaching 10 px into the next column
+        let grid = grid(&[circle(-2.0, 25.0, 12.0), aabb(40.0, -2.0, 8.0, 8.0)]);
+
+        assert_eq!(hits(&grid, aabb(8.0, 25.0, 2.0, 2.0)), [0]);
+        assert_eq!(hits(&grid, aabb(40.0, 4.0, 2.0, 2.0)), [1]);
+        assert!(hits(&grid, aabb(20.0, 25.0, 2.0, 2.0)).is_empty());
+    }
+
+    #[test]
+    fn finds_entries_larger_than_a_cell() {
+        // 160 px wide, centered two cells away from the query
+        let grid = grid(&[aabb(-75.0, -75.0, 80.0, 10.0), circle(75.0, 75.0, 5.0)]);
+
+        assert_eq!(hits(&grid, aabb(0.0, -75.0, 4.0, 4.0)), [0]);
+        assert!(hits(&grid, aabb(10.0, -75.0, 4.0, 4.0)).is_empty());
+    }
+
+    #[test]
+    fn clamps_entries_and_queries_outside_the_bounds() {
+        let grid = grid(&[
+            circle(-180.0, 0.0, 10.0),
+            circle(0.0, 250.0, 10.0),
+            circle(130.0, -130.0, 50.0),
+        ]);
+        assert_eq!(grid.len(), 3);
+
+        // Queries outside the rectangle still reach the edge cells
+        assert_eq!(hits(&grid, circle(-185.0, 5.0, 4.0)), [0]);
+        assert_eq!(hits(&grid, circle(0.0, 245.0, 4.0)), [1]);
+        // Parked off-grid but still overlapping the inside corner
+        assert_eq!(hits(&grid, aabb(95.0, -95.0, 4.0, 4.0)), [2]);
+        assert!(hits(&grid, circle(-100.0, 0.0, 4.0)).is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force_without_duplicates() {
+        let entries: Vec<Hitbox> = (0..200)
+            .map(|i| {
+                let t = i as f32;
+                let (x, y) = ((t * 37.0) % 300.0 - 150.0, (t * 53.0) % 300.0 - 150.0);
+                match i % 3 {
+                    0 => circle(x, y, 3.0 + t % 30.0),
+                    1 => aabb(x, y, 2.0 + t % 70.0, 4.0),
+                    _ => aabb(x, y, 5.0, 5.0),
+                }
+            })
+            .collect();
+        let grid = grid(&entries);
+
+        for area in [
+            aabb(0.0, 0.0, 25.0, 32.0),
+            circle(-90.0, 60.0, 40.0),
+            aabb(140.0, -140.0, 60.0, 10.0),
+        ] {
+            let expected: Vec<usize> = (0..entries.len())
+                .filter(|&i| entries[i].overlaps(&area))
+                .collect();
+            assert_eq!(hits(&grid, area), expected);
+        }
+    }
+}
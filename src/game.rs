@@ -0,0 +1,5821 @@
+use crate::collision::{ColliderShape, Hitbox, UniformGrid};
+use crate::{analytics, storage};
+use bevy::audio::{AudioSink, PlaybackMode, PlaybackSettings, Volume};
+use bevy::ecs::system::{Command, SystemParam};
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub mod bot;
+pub mod gym;
+pub mod sim;
+#[cfg(test)]
+mod tests;
+pub mod text;
+
+use bot::BotProfile;
+use sim::Snapshot;
+
+/* =======================
+   COMPONENTS
+======================= */
+#[derive(Component)]
+struct MenuBackground;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component, Default)]
+struct Velocity(Vec2);
+
+#[derive(Component)]
+struct Present {
+    golden: bool,
+}
+
+#[derive(Component)]
+struct ScoreText;
+
+#[derive(Component)]
+struct HeatText;
+
+#[derive(Component)]
+struct ComboText;
+
+#[derive(Component)]
+struct QuotaText;
+
+#[derive(Component)]
+struct MenuModesText;
+
+// Menu overlay with the saved lifetime stats (S)
+#[derive(Component)]
+struct StatsScreen;
+
+#[derive(Component)]
+struct StatsText;
+
+#[derive(Component)]
+struct LivesText;
+
+#[derive(Component)]
+struct DashText;
+
+#[derive(Component)]
+struct WeatherText;
+
+#[derive(Component)]
+struct WeatherVeil;
+
+#[derive(Component)]
+struct ScorePopup {
+    timer: Timer,
+}
+
+// Fading message; alpha starts above 1 so it holds before fading out
+#[derive(Component)]
+struct MessageText {
+    alpha: f32,
+}
+
+// Bottom-left system log; `show_notices` fills it from `Notice` events
+#[derive(Component)]
+struct LogPanel;
+
+#[derive(Component)]
+struct LogLine {
+    level: NoticeLevel,
+}
+#[derive(Resource)]
+struct HitFreeze {
+    timer: Timer,
+    active: bool,
+}
+#[derive(Component)]
+struct MenuUI;
+#[derive(Resource)]
+struct GameOverFade {
+    alpha: f32,
+}
+
+#[derive(Component)]
+struct Snowflake;
+
+#[derive(Resource, PartialEq)]
+enum GameState {
+    Menu,
+    Playing,
+    Crashed,
+}
+#[derive(Resource)]
+struct ScreenShake {
+    intensity: f32,
+}
+#[derive(Resource)]
+struct CrashSoundPlayed(bool);
+
+#[derive(Component)]
+struct Fan;
+
+#[derive(Resource)]
+struct FanSpawnTimer(Timer);
+#[derive(Resource)]
+struct SnowSpawnTimer(Timer);
+#[derive(Component)]
+struct SnowSpeed(f32);
+
+#[derive(Component)]
+struct CorruptedBit;
+
+// Sideways px per frame (boss volleys); regular bits fall straight
+#[derive(Component, Default)]
+struct BitDrift(f32);
+
+#[derive(Component)]
+struct GrinchBoss;
+
+// 🧝 Walks the mid-screen lane, pocketing presents and dropping coal
+#[derive(Component)]
+struct GrinchElf {
+    direction: f32,
+    coal: Timer,
+}
+
+#[derive(Component)]
+struct Coal;
+
+#[derive(Resource)]
+struct ElfSpawnTimer(Timer);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+enum CompanionKind {
+    // Trails Santa and grabs nearby presents
+    Reindeer,
+    // Hovers above Santa and eats one bit a minute
+    Elf,
+}
+
+#[derive(Component)]
+struct Companion {
+    kind: CompanionKind,
+    velocity: Vec2,
+}
+
+#[derive(Component)]
+struct CompanionText;
+
+#[derive(Component)]
+struct MissionText;
+
+// 📣 Things that happened this frame, for missions and anything else keeping score
+#[derive(Event, Clone, Copy)]
+enum GameEvent {
+    PresentCaught { golden: bool, heat: f32 },
+    FanCollected,
+    CoalDebugged,
+    BossDefeated,
+    Crashed(CrashCause),
+}
+
+#[derive(Component)]
+struct BossBar;
+
+#[derive(Component)]
+struct BossBarFill;
+
+#[derive(Component)]
+struct BossLabel;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum CollisionLayer {
+    Player,
+    Pickup,
+    Hazard,
+    Thief,
+    Collector,
+    Shield,
+    Ghost, // on screen but out of play
+}
+
+impl CollisionLayer {
+    // Layers that go looking for overlaps (everything else just sits in the grid)
+    fn is_active(self) -> bool {
+        matches!(
+            self,
+            CollisionLayer::Player
+                | CollisionLayer::Thief
+                | CollisionLayer::Collector
+                | CollisionLayer::Shield
+        )
+    }
+
+    // Player, thieves and companions report overlaps, only with things that matter
+    fn interacts_with(self, other: CollisionLayer) -> bool {
+        matches!(
+            (self, other),
+            (CollisionLayer::Player, CollisionLayer::Pickup)
+                | (CollisionLayer::Player, CollisionLayer::Hazard)
+                | (CollisionLayer::Thief, CollisionLayer::Pickup)
+                | (CollisionLayer::Collector, CollisionLayer::Pickup)
+                | (CollisionLayer::Shield, CollisionLayer::Hazard)
+        )
+    }
+}
+
+// Hitbox = sprite size × transform scale × fit.
+// `nominal_size` is the PNG size, used until the texture has loaded.
+#[derive(Component)]
+struct Collider {
+    layer: CollisionLayer,
+    shape: ColliderShape,
+    fit: f32,
+    nominal_size: Vec2,
+}
+
+impl Collider {
+    fn aabb(layer: CollisionLayer, nominal_size: Vec2, fit: f32) -> Self {
+        Self {
+            layer,
+            shape: ColliderShape::Aabb,
+            fit,
+            nominal_size,
+        }
+    }
+
+    fn circle(layer: CollisionLayer, nominal_size: Vec2, fit: f32) -> Self {
+        Self {
+            layer,
+            shape: ColliderShape::Circle,
+            fit,
+            nominal_size,
+        }
+    }
+}
+
+// `a` is the active side (player, thief or companion), `b` whatever it touched
+#[derive(Event)]
+struct Overlap {
+    a: Entity,
+    b: Entity,
+}
+
+#[derive(Resource, Default)]
+struct DebugOverlay(bool);
+
+// Set by `GamePlugin`: simulations and tests keep the profile and console untouched
+#[derive(Resource)]
+struct Headless(bool);
+
+#[derive(Component)]
+struct DebugText;
+
+// 🗺 Rebuilt every fixed tick right before `detect_collisions`
+#[derive(Resource)]
+struct Broadphase(UniformGrid<(Entity, CollisionLayer)>);
+
+impl Default for Broadphase {
+    fn default() -> Self {
+        Self(UniformGrid::new(
+            Vec2::new(-400.0, -400.0),
+            Vec2::new(400.0, 400.0),
+            64.0,
+        ))
+    }
+}
+
+#[derive(Resource)]
+#[allow(dead_code)]
+struct CorruptedSpawnTimer(Timer);
+
+#[derive(Resource)]
+struct CorruptedBitSpawnTimer(Timer);
+
+#[derive(Component)]
+struct BackgroundMusic;
+#[derive(Resource)]
+struct Difficulty {
+    level: f32,
+    time_alive: f32,
+}
+// 🎲 One seed drives spawns and weather, so a run can be replayed. Snow rolls
+// its own stream, so scenery never shifts a gameplay roll.
+#[derive(Resource, Deref, DerefMut)]
+struct GameRng {
+    seed: u64,
+    fixed: Option<u64>,
+    #[deref]
+    rng: StdRng,
+    scenery: StdRng,
+}
+
+impl GameRng {
+    fn new(fixed: Option<u64>) -> Self {
+        let seed = fixed.unwrap_or_else(|| rand::thread_rng().gen());
+
+        Self {
+            seed,
+            fixed,
+            rng: StdRng::seed_from_u64(seed),
+            scenery: StdRng::seed_from_u64(!seed),
+        }
+    }
+
+    // Same seed again if one is configured, a fresh one otherwise
+    fn restart(&mut self) {
+        *self = Self::new(self.fixed);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum WeatherKind {
+    Blizzard,
+    WindGust,
+    HeatWave,
+}
+
+impl WeatherKind {
+    fn title(self) -> &'static str {
+        match self {
+            WeatherKind::Blizzard => "BLIZZARD",
+            WeatherKind::WindGust => "WIND GUST",
+            WeatherKind::HeatWave => "HEAT WAVE",
+        }
+    }
+
+    fn detail(self) -> &'static str {
+        match self {
+            WeatherKind::Blizzard => "VISIBILITY DOWN - COOLING UP",
+            WeatherKind::WindGust => "FALLING OBJECTS DRIFT",
+            WeatherKind::HeatWave => "DATA CENTER AC FAILED",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            WeatherKind::Blizzard => Color::ALICE_BLUE,
+            WeatherKind::WindGust => Color::AQUAMARINE,
+            WeatherKind::HeatWave => Color::ORANGE,
+        }
+    }
+}
+
+// Scheduled by `update_difficulty`: calm → forecast warning → event → calm
+#[derive(Resource)]
+struct Weather {
+    // Own stream, so the schedule doesn't shift with how much has spawned
+    rng: StdRng,
+    forecast: WeatherKind,
+    calm: Timer,
+    current: Option<WeatherKind>,
+    event: Timer,
+    wind_direction: f32,
+}
+
+impl Weather {
+    const FIRST_EVENT: f32 = 40.0;
+    const WARNING: f32 = 3.0;
+    const RAMP: f32 = 1.5;
+
+    fn new(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed.rotate_left(32));
+        let forecast = Self::roll(&mut rng, 0.0);
+
+        Self {
+            rng,
+            forecast,
+            calm: Timer::from_seconds(Self::FIRST_EVENT, TimerMode::Once),
+            current: None,
+            event: Timer::from_seconds(0.0, TimerMode::Once),
+            wind_direction: 1.0,
+        }
+    }
+
+    // 🔥 Heat waves wait until heat matters (same 90 s as the director)
+    fn roll(rng: &mut StdRng, time_alive: f32) -> WeatherKind {
+        let kinds: &[WeatherKind] = if time_alive > 90.0 {
+            &[
+                WeatherKind::Blizzard,
+                WeatherKind::WindGust,
+                WeatherKind::HeatWave,
+            ]
+        } else {
+            &[WeatherKind::Blizzard, WeatherKind::WindGust]
+        };
+        kinds[rng.gen_range(0..kinds.len())]
+    }
+
+    // Returns the event that just started, if any
+    fn tick(
+        &mut self,
+        delta: std::time::Duration,
+        level: f32,
+        time_alive: f32,
+    ) -> Option<WeatherKind> {
+        if self.current.is_some() {
+            self.event.tick(delta);
+
+            if self.event.finished() {
+                // 🌤 Gaps tighten as the difficulty climbs
+                let gap = self.rng.gen_range(25.0..45.0) / level;
+                self.calm = Timer::from_seconds(gap.max(12.0), TimerMode::Once);
+                self.forecast = Self::roll(&mut self.rng, time_alive);
+                self.current = None;
+            }
+            return None;
+        }
+
+        self.calm.tick(delta);
+        if !self.calm.finished() {
+            return None;
+        }
+
+        let duration = self.rng.gen_range(8.0..14.0);
+        self.event = Timer::from_seconds(duration, TimerMode::Once);
+        self.wind_direction = if self.rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+        self.current = Some(self.forecast);
+        self.current
+    }
+
+    // 0 → 1 over the first and last RAMP seconds of an event
+    fn intensity(&self, kind: WeatherKind) -> f32 {
+        if self.current != Some(kind) {
+            return 0.0;
+        }
+
+        let edge = self.event.elapsed_secs().min(self.event.remaining_secs());
+        (edge / Self::RAMP).min(1.0)
+    }
+
+    // Sideways push on falling objects, px/s
+    fn wind(&self) -> f32 {
+        let gust = 0.7 + 0.3 * (self.event.elapsed_secs() * 2.5).sin();
+        self.wind_direction * 160.0 * gust * self.intensity(WeatherKind::WindGust)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum BossPhase {
+    // Counting down to the next encounter
+    Dormant,
+    // Warning + descent, no attacks yet
+    Intro,
+    Fighting,
+    // Beaten or out of time, flying off the top
+    Retreating,
+}
+
+#[derive(Clone, Copy)]
+enum BossAttack {
+    // `count` bits fanned over `spread` degrees
+    Fan { count: u32, spread: f32 },
+    // `count` bits scattered around the boss
+    Rain { count: u32 },
+    // One bit aimed at where Santa is now
+    Aimed,
+}
+
+struct BossStep {
+    wait: f32,
+    attack: BossAttack,
+}
+
+// 📜 Attack scripts, looped; the second one takes over below half health
+#[rustfmt::skip]
+const GRINCH_SCRIPT: &[BossStep] = &[
+    BossStep { wait: 1.6, attack: BossAttack::Fan { count: 3, spread: 40.0 } },
+    BossStep { wait: 1.2, attack: BossAttack::Aimed },
+    BossStep { wait: 1.8, attack: BossAttack::Rain { count: 4 } },
+    BossStep { wait: 1.2, attack: BossAttack::Aimed },
+];
+
+#[rustfmt::skip]
+const GRINCH_ENRAGED_SCRIPT: &[BossStep] = &[
+    BossStep { wait: 1.1, attack: BossAttack::Fan { count: 5, spread: 70.0 } },
+    BossStep { wait: 0.6, attack: BossAttack::Aimed },
+    BossStep { wait: 0.6, attack: BossAttack::Aimed },
+    BossStep { wait: 1.3, attack: BossAttack::Rain { count: 6 } },
+];
+
+#[derive(Resource)]
+struct Boss {
+    phase: BossPhase,
+    // Next encounter / descent / fight time limit, depending on the phase
+    timer: Timer,
+    health: u32,
+    max_health: u32,
+    step: usize,
+    attack: Timer,
+    flash: Timer,
+    encounters: u32,
+    // 🧊 Reward: overclocking makes no heat while this runs
+    cooldown: Timer,
+}
+
+impl Default for Boss {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(Self::COOLDOWN, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+
+        Self {
+            phase: BossPhase::Dormant,
+            timer: Timer::from_seconds(Self::FIRST_ENCOUNTER, TimerMode::Once),
+            health: 0,
+            max_health: 0,
+            step: 0,
+            attack: Timer::from_seconds(0.0, TimerMode::Once),
+            flash: Timer::from_seconds(0.12, TimerMode::Once),
+            encounters: 0,
+            cooldown,
+        }
+    }
+}
+
+impl Boss {
+    const FIRST_ENCOUNTER: f32 = 75.0;
+    const BETWEEN_ENCOUNTERS: f32 = 90.0;
+    const INTRO: f32 = 2.5;
+    const TIME_LIMIT: f32 = 40.0;
+    const COOLDOWN: f32 = 6.0;
+    const BONUS: u32 = 500;
+    const ARENA_Y: f32 = 250.0;
+    const OFFSCREEN_Y: f32 = 450.0;
+
+    fn is_active(&self) -> bool {
+        matches!(self.phase, BossPhase::Intro | BossPhase::Fighting)
+    }
+
+    fn is_cooling(&self) -> bool {
+        !self.cooldown.finished()
+    }
+
+    fn script(&self) -> &'static [BossStep] {
+        if self.health * 2 < self.max_health {
+            GRINCH_ENRAGED_SCRIPT
+        } else {
+            GRINCH_SCRIPT
+        }
+    }
+
+    fn start_encounter(&mut self) {
+        self.encounters += 1;
+        // 💪 Tougher every time it comes back
+        self.max_health = 8 + 4 * (self.encounters - 1);
+        self.health = self.max_health;
+        self.step = 0;
+        self.phase = BossPhase::Intro;
+        self.timer = Timer::from_seconds(Self::INTRO, TimerMode::Once);
+    }
+
+    // Returns true on the killing blow
+    fn damage(&mut self) -> bool {
+        if self.phase != BossPhase::Fighting || self.health == 0 {
+            return false;
+        }
+
+        self.health -= 1;
+        self.flash.reset();
+        self.health == 0
+    }
+}
+
+#[derive(Resource)]
+struct TimeScale {
+    value: f32,
+}
+
+#[derive(Component)]
+struct GameOverOverlay;
+
+#[derive(Resource)]
+struct GameData {
+    score: u32,
+    heat: f32,
+    speed_multiplier: f32,
+    overloading: bool,
+}
+
+impl Default for GameData {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            heat: 0.0,
+            speed_multiplier: 1.0,
+            overloading: false,
+        }
+    }
+}
+#[derive(Resource)]
+struct SpawnTimer(Timer);
+
+#[derive(Resource)]
+struct Combo {
+    count: u32,
+    decay: Timer,
+    was_hot: bool,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            decay: Timer::from_seconds(1.5, TimerMode::Repeating),
+            was_hot: false,
+        }
+    }
+}
+
+impl Combo {
+    // x1 → x5, one step every 5 catches
+    fn multiplier(&self) -> u32 {
+        (1 + self.count / 5).min(5)
+    }
+
+    fn add_catch(&mut self, overloading: bool) {
+        // 🔥 Overclocked catches build the meter twice as fast
+        self.count += if overloading { 2 } else { 1 };
+        self.decay.reset();
+    }
+
+    fn break_combo(&mut self) {
+        self.count = 0;
+        self.decay.reset();
+    }
+}
+
+#[derive(Resource, Default)]
+struct RunStats {
+    presents_collected: u32,
+    presents_missed: u32,
+    presents_stolen: u32,
+    golden_collected: u32,
+    coal_debugged: u32,
+    bosses_defeated: u32,
+    missions_completed: u32,
+    max_combo: u32,
+    continues_used: u32,
+    fans_collected: u32,
+    overclock_seconds: f32,
+    crash_cause: Option<CrashCause>,
+}
+
+impl RunStats {
+    // ♻ Two warm reboots per run
+    fn reboots_left(&self) -> u32 {
+        2_u32.saturating_sub(self.continues_used)
+    }
+}
+
+/// What ended a run.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub enum CrashCause {
+    Overheat,
+    Corrupted,
+    DeliveryFailed,
+}
+
+impl CrashCause {
+    fn title(&self) -> &'static str {
+        match self {
+            CrashCause::Overheat | CrashCause::Corrupted => "SYSTEM FAILURE",
+            CrashCause::DeliveryFailed => "DELIVERY FAILED",
+        }
+    }
+
+    fn detail(&self) -> &'static str {
+        match self {
+            CrashCause::Overheat => "CPU OVERHEATED",
+            CrashCause::Corrupted => "CORRUPTED BIT DETECTED",
+            CrashCause::DeliveryFailed => "QUOTA NOT MET",
+        }
+    }
+}
+
+// 📋 Typed snapshot of a run; lifetime stats are folded from these, never from GameData
+#[derive(Clone, Default, Serialize)]
+struct RunSummary {
+    score: u32,
+    seconds: f32,
+    presents_collected: u32,
+    presents_missed: u32,
+    presents_stolen: u32,
+    golden_collected: u32,
+    coal_debugged: u32,
+    bosses_defeated: u32,
+    missions_completed: u32,
+    max_combo: u32,
+    fans_used: u32,
+    overclock_seconds: f32,
+    continues_used: u32,
+    crash_cause: Option<CrashCause>,
+    crash_heat: f32,
+}
+
+impl RunSummary {
+    fn capture(stats: &RunStats, data: &GameData, difficulty: &Difficulty) -> Self {
+        Self {
+            score: data.score,
+            seconds: difficulty.time_alive,
+            presents_collected: stats.presents_collected,
+            presents_missed: stats.presents_missed,
+            presents_stolen: stats.presents_stolen,
+            golden_collected: stats.golden_collected,
+            coal_debugged: stats.coal_debugged,
+            bosses_defeated: stats.bosses_defeated,
+            missions_completed: stats.missions_completed,
+            max_combo: stats.max_combo,
+            fans_used: stats.fans_collected,
+            overclock_seconds: stats.overclock_seconds,
+            continues_used: stats.continues_used,
+            crash_cause: stats.crash_cause,
+            crash_heat: data.heat,
+        }
+    }
+}
+
+#[derive(Resource, Default, Deserialize)]
+#[serde(default)]
+struct GameConfig {
+    delivery_quota: bool,
+    lives: bool,
+    vertical_movement: bool,
+    movement: MovementConfig,
+    pools: PoolConfig,
+    seed: Option<u64>,
+    export_runs: bool,
+    // Some(profile) lets a bot play instead of the keyboard
+    bot: Option<BotProfile>,
+}
+
+impl GameConfig {
+    // Tuning fingerprint for exports; modes and seed are reported on their own
+    fn tuning_hash(&self) -> String {
+        let tuning = ron::to_string(&(&self.movement, &self.pools)).unwrap_or_default();
+        format!("{:016x}", analytics::fnv1a(tuning.as_bytes()))
+    }
+}
+
+// Hidden entities kept per kind (overflow spawns are despawned on release)
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct PoolConfig {
+    snowflake: usize,
+    present: usize,
+    fan: usize,
+    corrupted_bit: usize,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            snowflake: 768,
+            present: 32,
+            fan: 8,
+            corrupted_bit: 32,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct MovementConfig {
+    acceleration: f32,
+    deceleration: f32,
+    max_speed: f32,
+    overclock_momentum: f32,
+    max_lean_degrees: f32,
+    band_top: f32,
+    altitude_bonus: f32,
+}
+
+impl Default for MovementConfig {
+    fn default() -> Self {
+        Self {
+            acceleration: 2400.0,
+            deceleration: 3000.0,
+            max_speed: 300.0,
+            overclock_momentum: 0.6,
+            max_lean_degrees: 12.0,
+            band_top: -100.0,
+            altitude_bonus: 1.0,
+        }
+    }
+}
+
+impl MovementConfig {
+    // 🎅 Santa's home row (set in `setup`)
+    const FLOOR_Y: f32 = -250.0;
+
+    // 0 at the floor → 1 at the top of the band
+    fn altitude(&self, y: f32) -> f32 {
+        ((y - Self::FLOOR_Y) / (self.band_top - Self::FLOOR_Y).max(1.0)).clamp(0.0, 1.0)
+    }
+}
+
+impl GameConfig {
+    fn load() -> Self {
+        load_ron("assets/config.ron", include_str!("../assets/config.ron")).unwrap_or_default()
+    }
+}
+
+fn load_ron<T: DeserializeOwned>(path: &str, embedded: &str) -> Option<T> {
+    // 🖥 Native builds pick up edits without recompiling
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Ok(text) = std::fs::read_to_string(path) {
+        match ron::from_str(&text) {
+            Ok(value) => return Some(value),
+            Err(err) => println!("⚠ {path}: {err}"),
+        }
+    }
+
+    // 🌐 Web build uses the copy baked in at compile time
+    match ron::from_str(embedded) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            println!("⚠ {path} (embedded): {err}");
+            None
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+enum MissionGoal {
+    CollectWhileHot { count: u32, heat: f32 },
+    SurviveWithoutFan { seconds: f32 },
+    CatchGolden { count: u32 },
+    ReachCombo { count: u32 },
+    DebugCoal { count: u32 },
+    DefeatBoss,
+    Survive { seconds: f32 },
+}
+
+impl MissionGoal {
+    fn target(self) -> f32 {
+        match self {
+            MissionGoal::CollectWhileHot { count, .. }
+            | MissionGoal::CatchGolden { count }
+            | MissionGoal::ReachCombo { count }
+            | MissionGoal::DebugCoal { count } => count as f32,
+            MissionGoal::SurviveWithoutFan { seconds } | MissionGoal::Survive { seconds } => {
+                seconds
+            }
+            MissionGoal::DefeatBoss => 1.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+enum MissionReward {
+    Score(u32),
+    Cookies(u32),
+}
+
+impl MissionReward {
+    fn label(self) -> String {
+        match self {
+            MissionReward::Score(points) => format!("+{points}"),
+            MissionReward::Cookies(count) => format!("+{count} COOKIES"),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+struct MissionDef {
+    id: String,
+    description: String,
+    goal: MissionGoal,
+    reward: MissionReward,
+}
+
+// 📜 Every mission a run can draw, from assets/missions.ron
+#[derive(Resource, Default)]
+struct MissionBook(Vec<MissionDef>);
+
+impl MissionBook {
+    const PER_RUN: usize = 3;
+
+    fn load() -> Self {
+        let embedded = include_str!("../assets/missions.ron");
+        Self(load_ron("assets/missions.ron", embedded).unwrap_or_default())
+    }
+}
+
+struct Mission {
+    def: MissionDef,
+    progress: f32,
+    done: bool,
+}
+
+impl Mission {
+    fn status(&self) -> String {
+        let target = self.def.goal.target();
+        format!(
+            "[{}] {}  {:.0}/{:.0}",
+            if self.done { "X" } else { " " },
+            self.def.description,
+            self.progress.min(target).floor(),
+            target
+        )
+    }
+}
+
+#[derive(Resource, Default)]
+struct Missions {
+    active: Vec<Mission>,
+    // Current stretch without a fan (for SurviveWithoutFan)
+    since_fan: f32,
+}
+
+// 🍪 Meta-currency from missions, saved as soon as it's earned
+#[derive(Resource, Default)]
+struct Wallet {
+    cookies: u32,
+}
+
+impl Wallet {
+    const KEY: &'static str = "wallet";
+
+    fn load() -> Self {
+        Self {
+            cookies: storage::load(Self::KEY)
+                .and_then(|text| ron::from_str(&text).ok())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn save(&self) {
+        save_ron(Self::KEY, &self.cookies);
+    }
+}
+
+// 🏅 Checked against the current run, or lifetime totals including it
+#[derive(Clone, Copy)]
+enum Condition {
+    RunPresents(u32),
+    RunCombo(u32),
+    RunScore(u32),
+    RunSeconds(f32),
+    RunBosses(u32),
+    RunMissions(u32),
+    TotalPresents(u32),
+    TotalGolden(u32),
+    TotalCoal(u32),
+    TotalRuns(u32),
+}
+
+impl Condition {
+    fn met(self, run: &RunSummary, lifetime: &Totals) -> bool {
+        match self {
+            Condition::RunPresents(n) => run.presents_collected >= n,
+            Condition::RunCombo(n) => run.max_combo >= n,
+            Condition::RunScore(n) => run.score >= n,
+            Condition::RunSeconds(s) => run.seconds >= s,
+            Condition::RunBosses(n) => run.bosses_defeated >= n,
+            Condition::RunMissions(n) => run.missions_completed >= n,
+            Condition::TotalPresents(n) => lifetime.presents_collected >= n,
+            Condition::TotalGolden(n) => lifetime.golden_collected >= n,
+            Condition::TotalCoal(n) => lifetime.coal_debugged >= n,
+            Condition::TotalRuns(n) => lifetime.runs >= n,
+        }
+    }
+}
+
+struct AchievementDef {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    condition: Condition,
+}
+
+#[rustfmt::skip]
+const ACHIEVEMENTS: &[AchievementDef] = &[
+    AchievementDef { id: "first_delivery", name: "FIRST DELIVERY", description: "CATCH A PRESENT", condition: Condition::RunPresents(1) },
+    AchievementDef { id: "in_the_zone", name: "IN THE ZONE", description: "REACH A 10 COMBO", condition: Condition::RunCombo(10) },
+    AchievementDef { id: "high_clock", name: "HIGH CLOCK", description: "SCORE 5000 IN ONE RUN", condition: Condition::RunScore(5000) },
+    AchievementDef { id: "uptime", name: "UPTIME", description: "SURVIVE 180 S IN ONE RUN", condition: Condition::RunSeconds(180.0) },
+    AchievementDef { id: "antivirus", name: "ANTIVIRUS", description: "DEFEAT THE GRINCH VIRUS", condition: Condition::RunBosses(1) },
+    AchievementDef { id: "overachiever", name: "OVERACHIEVER", description: "COMPLETE 3 MISSIONS IN ONE RUN", condition: Condition::RunMissions(3) },
+    AchievementDef { id: "workshop", name: "SANTA'S WORKSHOP", description: "CATCH 500 PRESENTS IN TOTAL", condition: Condition::TotalPresents(500) },
+    AchievementDef { id: "gold_standard", name: "GOLD STANDARD", description: "CATCH 25 GOLDEN PRESENTS IN TOTAL", condition: Condition::TotalGolden(25) },
+    AchievementDef { id: "debugger", name: "DEBUGGER", description: "DEBUG 10 PIECES OF COAL IN TOTAL", condition: Condition::TotalCoal(10) },
+    AchievementDef { id: "veteran", name: "VETERAN", description: "PLAY 10 RUNS", condition: Condition::TotalRuns(10) },
+];
+
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Deaths {
+    overheat: u32,
+    corrupted: u32,
+    delivery_failed: u32,
+}
+
+impl Deaths {
+    fn add(&mut self, cause: CrashCause) {
+        match cause {
+            CrashCause::Overheat => self.overheat += 1,
+            CrashCause::Corrupted => self.corrupted += 1,
+            CrashCause::DeliveryFailed => self.delivery_failed += 1,
+        }
+    }
+}
+
+// Crash heat histogram: 0-9, 10-19 … 90+ °C
+const HEAT_BUCKETS: usize = 10;
+
+// Counters that add up across runs
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Totals {
+    runs: u32,
+    presents_collected: u32,
+    golden_collected: u32,
+    coal_debugged: u32,
+    bosses_defeated: u32,
+    seconds_played: f32,
+    best_score: u32,
+    total_score: u64,
+    best_seconds: f32,
+    overclock_seconds: f32,
+    fans_used: u32,
+    deaths: Deaths,
+    crash_heat: [u32; HEAT_BUCKETS],
+}
+
+impl Totals {
+    fn from_run(run: &RunSummary) -> Self {
+        Self {
+            runs: 1,
+            presents_collected: run.presents_collected,
+            golden_collected: run.golden_collected,
+            coal_debugged: run.coal_debugged,
+            bosses_defeated: run.bosses_defeated,
+            seconds_played: run.seconds,
+            best_score: run.score,
+            total_score: run.score.into(),
+            best_seconds: run.seconds,
+            overclock_seconds: run.overclock_seconds,
+            fans_used: run.fans_used,
+            ..default()
+        }
+    }
+
+    // `self` plus whatever part of `run` isn't in `folded` yet
+    fn merged(&self, run: &Totals, folded: &Totals) -> Self {
+        let add = |total: u32, now: u32, done: u32| total + now.saturating_sub(done);
+
+        Self {
+            runs: add(self.runs, run.runs, folded.runs),
+            presents_collected: add(
+                self.presents_collected,
+                run.presents_collected,
+                folded.presents_collected,
+            ),
+            golden_collected: add(
+                self.golden_collected,
+                run.golden_collected,
+                folded.golden_collected,
+            ),
+            coal_debugged: add(self.coal_debugged, run.coal_debugged, folded.coal_debugged),
+            bosses_defeated: add(
+                self.bosses_defeated,
+                run.bosses_defeated,
+                folded.bosses_defeated,
+            ),
+            seconds_played: self.seconds_played
+                + (run.seconds_played - folded.seconds_played).max(0.0),
+            best_score: self.best_score.max(run.best_score),
+            total_score: self.total_score + run.total_score.saturating_sub(folded.total_score),
+            best_seconds: self.best_seconds.max(run.best_seconds),
+            overclock_seconds: self.overclock_seconds
+                + (run.overclock_seconds - folded.overclock_seconds).max(0.0),
+            fans_used: add(self.fans_used, run.fans_used, folded.fans_used),
+            deaths: self.deaths,
+            crash_heat: self.crash_heat,
+        }
+    }
+
+    // Once per crash report; a warm reboot's crash is a death of its own
+    fn record_crash(&mut self, run: &RunSummary) {
+        let Some(cause) = run.crash_cause else {
+            return;
+        };
+        self.deaths.add(cause);
+
+        let bucket = ((run.crash_heat / 10.0) as usize).min(HEAT_BUCKETS - 1);
+        self.crash_heat[bucket] += 1;
+    }
+
+    fn average_score(&self) -> u64 {
+        self.total_score / u64::from(self.runs.max(1))
+    }
+}
+
+// 💾 Saved on every crash report
+#[derive(Resource, Default)]
+struct LifetimeStats {
+    totals: Totals,
+    // Part of the current run already saved (crash, then warm reboot)
+    folded: Totals,
+}
+
+impl LifetimeStats {
+    const KEY: &'static str = "lifetime";
+
+    fn load() -> Self {
+        let totals = storage::load(Self::KEY)
+            .and_then(|text| ron::from_str(&text).ok())
+            .unwrap_or_default();
+
+        Self {
+            totals,
+            folded: Totals::default(),
+        }
+    }
+
+    fn save(&self) {
+        save_ron(Self::KEY, &self.totals);
+    }
+}
+
+#[derive(Resource, Default)]
+struct Achievements {
+    unlocked: Vec<String>,
+}
+
+impl Achievements {
+    const KEY: &'static str = "achievements";
+
+    fn load() -> Self {
+        Self {
+            unlocked: storage::load(Self::KEY)
+                .and_then(|text| ron::from_str(&text).ok())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn save(&self) {
+        save_ron(Self::KEY, &self.unlocked);
+    }
+
+    fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.iter().any(|u| u == id)
+    }
+}
+
+fn save_ron<T: Serialize>(key: &str, value: &T) {
+    let result = ron::ser::to_string_pretty(value, Default::default())
+        .map_err(|err| err.to_string())
+        .and_then(|text| storage::save(key, &text));
+
+    if let Err(err) = result {
+        println!("⚠ couldn't save {key}: {err}");
+    }
+}
+
+// Ordered by priority: a full log drops lower levels first
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum NoticeLevel {
+    Info,
+    Ok,
+    Warn,
+    Unlock,
+    Fail,
+}
+
+impl NoticeLevel {
+    fn tag(&self) -> &'static str {
+        match self {
+            NoticeLevel::Info => "[INFO]",
+            NoticeLevel::Ok => "[OK]",
+            NoticeLevel::Warn => "[WARN]",
+            NoticeLevel::Unlock => "[UNLOCK]",
+            NoticeLevel::Fail => "[FAIL]",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            NoticeLevel::Info => Color::rgb(0.7, 0.75, 0.8),
+            NoticeLevel::Ok => Color::rgb(0.3, 1.0, 0.4),
+            NoticeLevel::Warn => Color::ORANGE,
+            NoticeLevel::Unlock => Color::GOLD,
+            NoticeLevel::Fail => Color::RED,
+        }
+    }
+
+    // Seconds a line stays fully visible
+    fn lifetime(&self) -> f32 {
+        match self {
+            NoticeLevel::Info | NoticeLevel::Ok => 3.0,
+            NoticeLevel::Warn => 4.0,
+            NoticeLevel::Unlock | NoticeLevel::Fail => 5.0,
+        }
+    }
+}
+
+// Posted by gameplay systems; only `show_notices` knows about the log panel
+#[derive(Event, Clone)]
+struct Notice {
+    level: NoticeLevel,
+    text: String,
+    lifetime: f32,
+}
+
+impl Notice {
+    fn new(level: NoticeLevel, text: impl Into<String>) -> Self {
+        Self {
+            level,
+            text: text.into(),
+            lifetime: level.lifetime(),
+        }
+    }
+
+    fn lasting(mut self, seconds: f32) -> Self {
+        self.lifetime = seconds;
+        self
+    }
+}
+
+// Notices waiting for a line, highest priority first (FIFO within a level)
+#[derive(Resource, Default)]
+struct SystemLog {
+    queue: Vec<Notice>,
+}
+
+impl SystemLog {
+    const MAX_LINES: usize = 5;
+    const MAX_QUEUED: usize = 12;
+    const LINE_INTERVAL: f32 = 0.12;
+
+    fn push(&mut self, notice: Notice) {
+        let at = self.queue.partition_point(|n| n.level >= notice.level);
+        self.queue.insert(at, notice);
+        self.queue.truncate(Self::MAX_QUEUED);
+    }
+
+    fn pop(&mut self) -> Option<Notice> {
+        (!self.queue.is_empty()).then(|| self.queue.remove(0))
+    }
+
+    // Back to the front when there is no room for it yet
+    fn requeue(&mut self, notice: Notice) {
+        self.queue.insert(0, notice);
+    }
+}
+
+#[derive(Resource)]
+struct Lives {
+    remaining: u32,
+    invulnerable: Timer,
+}
+
+impl Default for Lives {
+    fn default() -> Self {
+        let mut invulnerable = Timer::from_seconds(1.5, TimerMode::Once);
+        invulnerable.tick(invulnerable.duration());
+
+        Self {
+            remaining: 3,
+            invulnerable,
+        }
+    }
+}
+
+impl Lives {
+    fn is_invulnerable(&self) -> bool {
+        !self.invulnerable.finished()
+    }
+}
+
+// 🦌 Unlocked by milestones, kept for the session
+#[derive(Resource)]
+struct Companions {
+    reindeer_unlocked: bool,
+    elf_unlocked: bool,
+    selected: Option<CompanionKind>,
+    shield: Timer,
+    last_unlocked: Option<CompanionKind>,
+    unlocked_flash: Timer,
+}
+
+impl Default for Companions {
+    fn default() -> Self {
+        let mut shield = Timer::from_seconds(Self::SHIELD_COOLDOWN, TimerMode::Once);
+        shield.tick(shield.duration());
+        let mut unlocked_flash = Timer::from_seconds(3.0, TimerMode::Once);
+        unlocked_flash.tick(unlocked_flash.duration());
+
+        Self {
+            reindeer_unlocked: false,
+            elf_unlocked: false,
+            selected: None,
+            shield,
+            last_unlocked: None,
+            unlocked_flash,
+        }
+    }
+}
+
+impl Companions {
+    const SHIELD_COOLDOWN: f32 = 60.0;
+    const REINDEER_PRESENTS: u32 = 25;
+    const ELF_SECONDS: f32 = 120.0;
+
+    fn unlocked(&self) -> Vec<CompanionKind> {
+        let mut kinds = Vec::new();
+        if self.reindeer_unlocked {
+            kinds.push(CompanionKind::Reindeer);
+        }
+        if self.elf_unlocked {
+            kinds.push(CompanionKind::Elf);
+        }
+        kinds
+    }
+
+    // None → each unlocked companion → None
+    fn cycle(&mut self) {
+        let unlocked = self.unlocked();
+        let next = match self.selected {
+            None => 0,
+            Some(kind) => unlocked
+                .iter()
+                .position(|k| *k == kind)
+                .map_or(0, |i| i + 1),
+        };
+        self.selected = unlocked.get(next).copied();
+    }
+
+    fn unlock(&mut self, kind: CompanionKind) {
+        match kind {
+            CompanionKind::Reindeer => self.reindeer_unlocked = true,
+            CompanionKind::Elf => self.elf_unlocked = true,
+        }
+
+        // 🎁 Join straight away if nobody is along yet
+        if self.selected.is_none() {
+            self.selected = Some(kind);
+        }
+        self.last_unlocked = Some(kind);
+        self.unlocked_flash.reset();
+    }
+}
+
+#[derive(Resource)]
+struct Dash {
+    direction: f32,
+    facing: f32,
+    active: Timer,
+    cooldown: Timer,
+}
+
+impl Default for Dash {
+    fn default() -> Self {
+        let mut active = Timer::from_seconds(0.12, TimerMode::Once);
+        active.tick(active.duration());
+        let mut cooldown = Timer::from_seconds(2.0, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+
+        Self {
+            direction: 0.0,
+            facing: 1.0,
+            active,
+            cooldown,
+        }
+    }
+}
+
+impl Dash {
+    fn is_dashing(&self) -> bool {
+        !self.active.finished()
+    }
+}
+
+#[derive(Resource)]
+struct Checkpoint {
+    score: u32,
+    level: f32,
+    time_alive: f32,
+    timer: Timer,
+}
+
+impl Default for Checkpoint {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            level: 1.0,
+            time_alive: 0.0,
+            timer: Timer::from_seconds(20.0, TimerMode::Repeating),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct DeliveryQuota {
+    phase: u32,
+    timer: Timer,
+    delivered_at_start: u32,
+    missed_at_start: u32,
+}
+
+impl Default for DeliveryQuota {
+    fn default() -> Self {
+        Self {
+            phase: 1,
+            timer: Timer::from_seconds(30.0, TimerMode::Repeating),
+            delivered_at_start: 0,
+            missed_at_start: 0,
+        }
+    }
+}
+
+impl DeliveryQuota {
+    // 📦 8 presents in phase 1, +2 every phase after
+    fn required(&self) -> u32 {
+        (8 + (self.phase - 1) * 2).min(20)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct HighScore {
+    score: u32,
+    max_combo: u32,
+    continues: u32,
+}
+
+#[derive(Resource, Default)]
+struct HighScores(Vec<HighScore>);
+
+impl HighScores {
+    fn record(&mut self, entry: HighScore) {
+        self.0.push(entry);
+        self.0.sort_by_key(|e| std::cmp::Reverse(e.score));
+        self.0.truncate(5);
+    }
+
+    // ♻ A warm reboot un-ends the run, so its entry goes back out
+    fn retract(&mut self, entry: HighScore) {
+        if let Some(i) = self.0.iter().position(|e| *e == entry) {
+            self.0.remove(i);
+        }
+    }
+}
+
+#[derive(Resource)]
+struct HighScoreRecorded(bool);
+
+/* =======================
+   SYSTEM PARAMS
+======================= */
+
+// ⏱ The fixed tick the round runs on: whether it's running, how long the tick
+// was, slow motion, difficulty and wind
+#[derive(SystemParam)]
+struct Tick<'w> {
+    state: Res<'w, GameState>,
+    time: Res<'w, Time>,
+    time_scale: Res<'w, TimeScale>,
+    difficulty: Res<'w, Difficulty>,
+    weather: Res<'w, Weather>,
+}
+
+impl Tick<'_> {
+    fn playing(&self) -> bool {
+        *self.state == GameState::Playing
+    }
+}
+
+// 📈 What a run racks up: score and heat, the stats, the combo
+#[derive(SystemParam)]
+struct RunTally<'w> {
+    data: ResMut<'w, GameData>,
+    stats: ResMut<'w, RunStats>,
+    combo: ResMut<'w, Combo>,
+}
+
+// 💥 What a gameplay moment can set off: sprites and sounds, a screen shake,
+// a log line, a gameplay event
+#[derive(SystemParam)]
+struct Effects<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    shake: ResMut<'w, ScreenShake>,
+    notices: EventWriter<'w, Notice>,
+    events: EventWriter<'w, GameEvent>,
+}
+
+// 🤝 Overlaps only count during a round; outside one they're dropped unread
+#[derive(SystemParam)]
+struct RoundOverlaps<'w, 's> {
+    state: Res<'w, GameState>,
+    overlaps: EventReader<'w, 's, Overlap>,
+}
+
+impl RoundOverlaps<'_, '_> {
+    fn read(&mut self) -> Option<impl Iterator<Item = &Overlap>> {
+        if *self.state != GameState::Playing {
+            self.overlaps.clear();
+            return None;
+        }
+        Some(self.overlaps.read())
+    }
+}
+
+// 💾 Progress kept between runs, saved unless headless
+#[derive(SystemParam)]
+struct Profile<'w> {
+    headless: Res<'w, Headless>,
+    scores: ResMut<'w, HighScores>,
+    wallet: ResMut<'w, Wallet>,
+    lifetime: ResMut<'w, LifetimeStats>,
+    achievements: ResMut<'w, Achievements>,
+}
+
+/* =======================
+   MAIN
+======================= */
+
+// ⏱ Spawn timers and time effects as a round starts; `reset_round` puts them
+// back so a reseeded round replays the first one
+fn insert_round_clocks(world: &mut World) {
+    world.insert_resource(SpawnTimer(Timer::from_seconds(1.0, TimerMode::Repeating)));
+    world.insert_resource(FanSpawnTimer(Timer::from_seconds(
+        5.0,
+        TimerMode::Repeating,
+    )));
+    world.insert_resource(CorruptedSpawnTimer(Timer::from_seconds(
+        2.5,
+        TimerMode::Repeating,
+    )));
+    world.insert_resource(CorruptedBitSpawnTimer(Timer::from_seconds(
+        2.5,
+        TimerMode::Repeating,
+    )));
+    world.insert_resource(ElfSpawnTimer(Timer::from_seconds(30.0, TimerMode::Once)));
+    world.insert_resource(SnowSpawnTimer(Timer::from_seconds(
+        0.05,
+        TimerMode::Repeating,
+    )));
+    // Held at 0 outside a round, so every round eases in from a standstill
+    world.insert_resource(TimeScale { value: 0.0 });
+    world.insert_resource(ScreenShake { intensity: 0.0 });
+}
+
+/// Everything the game adds on top of Bevy's plugins.
+///
+/// The window build runs it after `DefaultPlugins`; simulations and tests run it
+/// headless on `MinimalPlugins` (see [`sim`]).
+pub struct GamePlugin {
+    /// No debug gizmos, no console chatter and no profile saves.
+    pub headless: bool,
+}
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        let config = GameConfig::load();
+        let rng = GameRng::new(config.seed);
+        let weather = Weather::new(rng.seed);
+        let input = config.bot.map_or(InputSource::Keyboard, InputSource::Bot);
+
+        // 💾 Headless runs start from a blank profile and never save it
+        let (lifetime, heatmap, achievements, wallet) = if self.headless {
+            Default::default()
+        } else {
+            println!("🎲 SEED {}", rng.seed);
+            (
+                LifetimeStats::load(),
+                Heatmap::load(),
+                Achievements::load(),
+                Wallet::load(),
+            )
+        };
+
+        insert_round_clocks(&mut app.world);
+        app.insert_resource(ClearColor(Color::BLACK))
+            .insert_resource(GameData::default())
+            .insert_resource(GameState::Menu)
+            .insert_resource(CrashSoundPlayed(false))
+            .insert_resource(Combo::default())
+            .insert_resource(RunStats::default())
+            .insert_resource(HighScores::default())
+            .insert_resource(HighScoreRecorded(false))
+            .insert_resource(config)
+            .insert_resource(rng)
+            .insert_resource(weather)
+            .insert_resource(Boss::default())
+            .insert_resource(Companions::default())
+            .insert_resource(MissionBook::load())
+            .insert_resource(Missions::default())
+            .insert_resource(wallet)
+            .insert_resource(Headless(self.headless))
+            .insert_resource(input)
+            .insert_resource(Actions::default())
+            .insert_resource(Snapshot::default())
+            .insert_resource(lifetime)
+            .insert_resource(RunTelemetry::default())
+            .insert_resource(heatmap)
+            .insert_resource(achievements)
+            .add_event::<GameEvent>()
+            .insert_resource(SystemLog::default())
+            .add_event::<Notice>()
+            .insert_resource(DeliveryQuota::default())
+            .insert_resource(Lives::default())
+            .insert_resource(Checkpoint::default())
+            .insert_resource(Dash::default())
+            .insert_resource(DebugOverlay::default())
+            .insert_resource(Broadphase::default())
+            .add_event::<Overlap>()
+            .insert_resource(Difficulty {
+                level: 1.0,
+                time_alive: 0.0,
+            })
+            .insert_resource(HitFreeze {
+                timer: Timer::from_seconds(0.15, TimerMode::Once),
+                active: false,
+            })
+            .insert_resource(GameOverFade { alpha: 0.0 })
+            .insert_resource(EntityPools::default())
+            // ⏱ Gameplay is tuned per 60 Hz frame
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .add_systems(
+                Startup,
+                (setup, setup_ui, setup_menu, play_music, prewarm_pools),
+            )
+            .add_systems(
+                PreUpdate,
+                (keyboard_actions, bot::bot_actions).after(bevy::input::InputSystem),
+            )
+            .add_systems(PostUpdate, sim::capture_snapshot)
+            .add_systems(Update, set_background_music_volume)
+            .add_systems(Update, ((menu_modes, menu_input).chain(), stats_screen))
+            // ⏱ One gameplay tick, always in this order so a seed replays exactly:
+            // clock, spawns, movement, collisions, what they caused, end of round
+            .add_systems(
+                FixedUpdate,
+                (
+                    (
+                        update_difficulty,
+                        sample_telemetry,
+                        overload_system,
+                        near_crash_slow_motion,
+                        assign_missions,
+                        unlock_companions,
+                        sync_companion,
+                    )
+                        .chain(),
+                    (
+                        spawn_presents,
+                        spawn_fans,
+                        spawn_corrupted_bits,
+                        spawn_grinch_elves,
+                    )
+                        .chain(),
+                    (
+                        dash_system,
+                        player_movement,
+                        steer_companions,
+                        move_presents,
+                        move_fans,
+                        move_corrupted_bits,
+                        move_grinch_elves,
+                        move_coal,
+                    )
+                        .chain(),
+                    (rebuild_broadphase, detect_collisions).chain(),
+                    (
+                        collect_presents,
+                        collect_fans,
+                        hit_corrupted_bits,
+                        companion_shield,
+                        elf_steal_presents,
+                        hit_coal,
+                    )
+                        .chain(),
+                    (boss_director, boss_attacks).chain(),
+                    (
+                        combo_decay,
+                        invulnerability_flash,
+                        checkpoint_system,
+                        delivery_quota_system,
+                        hit_freeze_system,
+                        crash_check,
+                    )
+                        .chain(),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    corrupted_bit_hit_sound,
+                    restore_background_music,
+                    camera_shake,
+                ),
+            )
+            .add_systems(Update, (toggle_debug_overlay, update_debug_text))
+            .add_systems(Update, animate_score_popups)
+            // ❄️ Flakes go back to the pool before new ones take from it, and
+            // snow starts with the round, so the snow replays too
+            .add_systems(
+                Update,
+                (move_snow, spawn_snowflakes)
+                    .chain()
+                    .after(menu_input)
+                    .after(restart_game),
+            )
+            .add_systems(Update, (apply_weather_veil, update_weather_text))
+            .add_systems(Update, update_boss_bar)
+            .add_systems(Update, (animate_companions, update_companion_text))
+            .add_systems(Update, (track_missions, update_mission_text).chain())
+            .add_systems(
+                Update,
+                (
+                    record_lifetime_stats,
+                    export_run.before(restart_game),
+                    save_heatmap.before(restart_game),
+                    evaluate_achievements.after(track_missions),
+                    log_gameplay_events,
+                    queue_notices,
+                    show_notices,
+                    fade_messages,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    // 🏆 Crashes land in the fixed tick, so the overlay lists this run too
+                    record_high_score.before(spawn_game_over_overlay),
+                    spawn_game_over_overlay,
+                    fade_game_over,
+                    update_ui,
+                    restart_game,
+                    warm_reboot,
+                ),
+            );
+
+        if !self.headless {
+            app.add_systems(Update, (draw_hitboxes, draw_heatmap));
+        }
+    }
+}
+
+/* =======================
+   SETUP
+======================= */
+fn set_background_music_volume(
+    state: Res<GameState>,
+    bg_music: Query<&AudioSink, With<BackgroundMusic>>,
+) {
+    let Ok(sink) = bg_music.get_single() else {
+        return;
+    };
+
+    match *state {
+        GameState::Menu => {
+            sink.set_volume(0.35); // calm menu
+        }
+        GameState::Playing => {
+            sink.set_volume(0.55); // main gameplay
+        }
+        GameState::Crashed => {
+            sink.set_volume(0.0); // silence on crash
+        }
+    }
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // 🎅 Santa sprite
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/santa.png"),
+            transform: Transform {
+                translation: Vec3::new(0.0, MovementConfig::FLOOR_Y, 0.0),
+                scale: Vec3::splat(1.0),
+                ..default()
+            },
+            visibility: Visibility::Hidden, // 👈 correct
+            ..default()
+        },
+        Player,
+        Velocity::default(),
+        Collider::aabb(CollisionLayer::Player, Vec2::new(167.0, 215.0), 0.3),
+    ));
+
+    // 🌫 Weather tint over the playfield (clear until an event)
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::NONE,
+                custom_size: Some(Vec2::splat(2000.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 5.0),
+            ..default()
+        },
+        WeatherVeil,
+    ));
+}
+
+fn camera_shake(
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+    data: Res<GameData>,
+    state: Res<GameState>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    let Ok(mut cam) = camera.get_single_mut() else {
+        return;
+    };
+
+    // Increase shake when overloading
+    if data.overloading {
+        shake.intensity = (shake.intensity + 0.8).clamp(0.0, 6.0);
+    } else {
+        shake.intensity *= 0.9;
+    }
+
+    // ✅ SAFETY CHECK (THIS FIXES THE CRASH)
+    if shake.intensity < 0.01 {
+        cam.translation.x = 0.0;
+        cam.translation.y = 0.0;
+        shake.intensity = 0.0;
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    cam.translation.x = rng.gen_range(-shake.intensity..shake.intensity);
+    cam.translation.y = rng.gen_range(-shake.intensity..shake.intensity);
+}
+fn spawn_fans(
+    mut commands: Commands,
+    state: Res<GameState>,
+    time: Res<Time>,
+    mut timer: ResMut<FanSpawnTimer>,
+    difficulty: Res<Difficulty>, // ✅ REQUIRED
+    mut rng: ResMut<GameRng>,
+    mut heatmap: ResMut<Heatmap>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+
+    timer.0.set_duration(std::time::Duration::from_secs_f32(
+        (5.0_f32 * difficulty.level).clamp(3.0_f32, 8.0_f32),
+    ));
+
+    if timer.0.just_finished() {
+        let x = rng.gen_range(-300.0_f32..300.0_f32);
+        heatmap.record(HeatmapChannel::FanSpawn, x);
+
+        commands.add(SpawnPooled::new(
+            PoolKind::Fan,
+            Transform {
+                translation: Vec3::new(x, 300.0, 0.0),
+                scale: Vec3::splat(0.6),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn move_fans(
+    mut commands: Commands,
+    tick: Tick,
+    mut query: Query<(Entity, &mut Transform, &Pooled), With<Fan>>,
+) {
+    if !tick.playing() {
+        return;
+    }
+
+    for (entity, mut transform, pooled) in query.iter_mut() {
+        if !pooled.active {
+            continue;
+        }
+
+        transform.translation.y -= 2.0 * tick.difficulty.level * tick.time_scale.value;
+        drift(
+            &mut transform,
+            &tick.weather,
+            tick.time.delta_seconds() * tick.time_scale.value,
+        );
+
+        if transform.translation.y < -360.0 {
+            commands.add(ReleasePooled(entity));
+        }
+    }
+}
+
+fn collect_fans(
+    mut fx: Effects,
+    mut run: RunTally,
+    mut overlaps: RoundOverlaps,
+    players: Query<(), With<Player>>,
+    mut fans: Query<&mut Collider, With<Fan>>,
+) {
+    let Some(overlaps) = overlaps.read() else {
+        return;
+    };
+
+    for overlap in overlaps {
+        // 🧝 Elves walk past fans
+        if !players.contains(overlap.a) {
+            continue;
+        }
+        let Ok(mut collider) = fans.get_mut(overlap.b) else {
+            continue;
+        };
+        if collider.layer == CollisionLayer::Ghost {
+            continue; // already grabbed this tick
+        }
+
+        // ❄ Reduce heat
+        run.data.heat = (run.data.heat - 25.0).clamp(0.0, 100.0);
+        run.stats.fans_collected += 1;
+        fx.events.send(GameEvent::FanCollected);
+
+        // 🔊 Play fan sound ONCE
+        fx.commands.spawn(AudioBundle {
+            source: fx.asset_server.load("audio/Wind2.wav"),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Once,
+                volume: Volume::new(0.4), // clean & soft
+                ..default()
+            },
+        });
+
+        // 🔒 Mark as collected immediately
+        collider.layer = CollisionLayer::Ghost;
+
+        // 🧹 Back to the pool
+        fx.commands.add(ReleasePooled(overlap.b));
+    }
+}
+
+fn spawn_corrupted_bits(
+    mut commands: Commands,
+    state: Res<GameState>,
+    time: Res<Time>,
+    mut timer: ResMut<CorruptedBitSpawnTimer>,
+    difficulty: Res<Difficulty>, // ✅ REQUIRED
+    mut rng: ResMut<GameRng>,
+    boss: Res<Boss>,
+) {
+    // 👾 The Grinch brings its own bits
+    if *state != GameState::Playing || boss.is_active() {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+
+    timer.0.set_duration(std::time::Duration::from_secs_f32(
+        (2.5_f32 / difficulty.level).clamp(0.6_f32, 2.5_f32),
+    ));
+
+    if timer.0.just_finished() {
+        let x = rng.gen_range(-300.0_f32..300.0_f32);
+
+        commands.add(
+            SpawnPooled::new(
+                PoolKind::CorruptedBit,
+                Transform {
+                    translation: Vec3::new(x, 300.0, 0.0),
+                    scale: Vec3::splat(0.8),
+                    ..default()
+                },
+            )
+            .with(BitDrift(0.0)),
+        );
+    }
+}
+
+// 🔻 px a bit falls per tick before time scale; the boss aims with it too
+fn bit_fall(difficulty: &Difficulty, data: &GameData) -> f32 {
+    4.5 * difficulty.level * data.speed_multiplier
+}
+
+fn move_corrupted_bits(
+    mut commands: Commands,
+    tick: Tick,
+    data: Res<GameData>,
+    mut query: Query<(Entity, &mut Transform, &BitDrift, &Pooled), With<CorruptedBit>>,
+) {
+    if !tick.playing() {
+        return;
+    }
+
+    for (entity, mut transform, bit_drift, pooled) in query.iter_mut() {
+        if !pooled.active {
+            continue;
+        }
+
+        transform.translation.y -= bit_fall(&tick.difficulty, &data) * tick.time_scale.value;
+        transform.translation.x += bit_drift.0 * tick.time_scale.value;
+        drift(
+            &mut transform,
+            &tick.weather,
+            tick.time.delta_seconds() * tick.time_scale.value,
+        );
+
+        if transform.translation.y < -350.0 {
+            commands.add(ReleasePooled(entity));
+        }
+    }
+}
+
+// 🛡 What stands between Santa and a bit: a spare life, i-frames, a dash,
+// a freeze already under way
+#[derive(SystemParam)]
+struct Guard<'w> {
+    config: Res<'w, GameConfig>,
+    freeze: ResMut<'w, HitFreeze>,
+    lives: ResMut<'w, Lives>,
+    dash: Res<'w, Dash>,
+}
+
+impl Guard<'_> {
+    fn shielded(&self) -> bool {
+        self.freeze.active || self.lives.is_invulnerable() || self.dash.is_dashing()
+    }
+}
+
+fn hit_corrupted_bits(
+    mut fx: Effects,
+    mut guard: Guard,
+    mut combo: ResMut<Combo>,
+    mut overlaps: RoundOverlaps,
+    players: Query<&Transform, With<Player>>,
+    bits: Query<(), With<CorruptedBit>>,
+    mut heatmap: ResMut<Heatmap>,
+) {
+    let Some(overlaps) = overlaps.read() else {
+        return;
+    };
+
+    for overlap in overlaps {
+        let Ok(santa) = players.get(overlap.a) else {
+            continue;
+        };
+        if !bits.contains(overlap.b) {
+            continue;
+        }
+
+        // 🛡 i-frames from a lost life or an active dash
+        if guard.shielded() {
+            continue;
+        }
+        heatmap.record(HeatmapChannel::BitHit, santa.translation.x);
+
+        // 🛡 Spend a life instead of crashing
+        if guard.config.lives && guard.lives.remaining > 1 {
+            guard.lives.remaining -= 1;
+            guard.lives.invulnerable.reset();
+            fx.notices.send(Notice::new(
+                NoticeLevel::Warn,
+                format!("BIT FAULT - {} LIVES LEFT", guard.lives.remaining),
+            ));
+            combo.break_combo();
+            fx.shake.intensity = 6.0;
+
+            fx.commands.spawn(AudioBundle {
+                source: fx.asset_server.load("audio/GameOver2.wav"),
+                settings: PlaybackSettings {
+                    mode: PlaybackMode::Once,
+                    volume: Volume::new(0.4),
+                    ..default()
+                },
+            });
+
+            fx.commands.add(ReleasePooled(overlap.b));
+            continue;
+        }
+
+        // ❄️ HIT FREEZE
+        guard.freeze.active = true;
+        guard.freeze.timer.reset();
+
+        // 🔊 SYSTEM FAILURE SOUND
+        fx.commands.spawn(AudioBundle {
+            source: fx.asset_server.load("audio/GameOver2.wav"),
+            settings: PlaybackSettings {
+                volume: Volume::new(0.9), // 🎬 cinematic punch
+                ..default()
+            },
+        });
+
+        // 🧹 REMOVE BIT
+        fx.commands.add(ReleasePooled(overlap.b));
+    }
+}
+
+fn hit_freeze_system(
+    time: Res<Time>,
+    mut freeze: ResMut<HitFreeze>,
+    mut state: ResMut<GameState>,
+    mut stats: ResMut<RunStats>,
+    mut events: EventWriter<GameEvent>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    if !freeze.active {
+        return;
+    }
+
+    freeze.timer.tick(time.delta());
+
+    if freeze.timer.finished() {
+        freeze.active = false;
+        *state = GameState::Crashed;
+        stats.crash_cause = Some(CrashCause::Corrupted);
+        events.send(GameEvent::Crashed(CrashCause::Corrupted));
+    }
+}
+
+fn corrupted_bit_hit_sound(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    bg_music: Query<&AudioSink, With<BackgroundMusic>>,
+    mut played: ResMut<CrashSoundPlayed>,
+    state: Res<GameState>,
+) {
+    // Only play once, only on crash
+    if *state != GameState::Crashed || played.0 {
+        return;
+    }
+
+    // 🔇 Mute background music
+    if let Ok(sink) = bg_music.get_single() {
+        sink.set_volume(0.0);
+    }
+
+    // 🔊 Play crash sound ONCE
+    commands.spawn(AudioBundle {
+        source: asset_server.load("audio/GameOver2.wav"),
+        settings: PlaybackSettings {
+            mode: PlaybackMode::Once,
+            volume: Volume::new(0.9), // clean, not harsh
+            ..default()
+        },
+    });
+
+    played.0 = true; // 🔒 LOCK IT
+}
+
+fn restore_background_music(bg_music: Query<&AudioSink, With<BackgroundMusic>>) {
+    if let Ok(sink) = bg_music.get_single() {
+        sink.set_volume(0.3); // 🔉 restore background
+    }
+}
+
+fn update_difficulty(
+    time: Res<Time>,
+    data: Res<GameData>,
+    mut difficulty: ResMut<Difficulty>,
+    mut weather: ResMut<Weather>,
+    state: Res<GameState>,
+    mut notices: EventWriter<Notice>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    // ⏱ Track survival time
+    difficulty.time_alive += time.delta_seconds();
+
+    // 🐢 VERY SLOW BASE RAMP (Subway Surfers style)
+    let base_growth = if difficulty.time_alive < 60.0 {
+        0.002 // first 1 minute = chill
+    } else if difficulty.time_alive < 120.0 {
+        0.004 // minute 2–3 = tension
+    } else {
+        0.006 // late game
+    };
+
+    difficulty.level += base_growth * time.delta_seconds();
+
+    // 🔥 Heat only matters AFTER 90 seconds
+    if difficulty.time_alive > 90.0 {
+        let heat_factor = (data.heat / 100.0).powf(1.8);
+        difficulty.level += heat_factor * 0.003;
+    }
+
+    // 🛑 Hard cap (never unfair)
+    difficulty.level = difficulty.level.clamp(1.0, 3.2);
+
+    // 🌨 Weather events come from the same director
+    if let Some(kind) = weather.tick(time.delta(), difficulty.level, difficulty.time_alive) {
+        notices.send(Notice::new(
+            NoticeLevel::Warn,
+            format!("{}: {}", kind.title(), kind.detail()),
+        ));
+    }
+}
+
+fn near_crash_slow_motion(
+    mut time_scale: ResMut<TimeScale>,
+    data: Res<GameData>,
+    state: Res<GameState>,
+    mut notices: EventWriter<Notice>,
+    mut throttling: Local<bool>,
+) {
+    if *state != GameState::Playing {
+        time_scale.value = 0.0;
+        *throttling = false;
+        return;
+    }
+
+    // 🌡 Log once per trip into the red zone
+    if data.heat >= 85.0 && !*throttling {
+        notices.send(Notice::new(
+            NoticeLevel::Warn,
+            format!("CPU TEMP {:.0}°C - THROTTLING", data.heat),
+        ));
+    }
+    *throttling = data.heat >= 85.0;
+
+    // 🔥 Near crash zone
+    if data.heat >= 85.0 {
+        let t = (data.heat - 85.0) / 15.0; // 0 → 1
+        time_scale.value = (1.0 - t * 0.5).clamp(0.5, 1.0);
+    } else {
+        // Smooth recovery
+        time_scale.value += (1.0 - time_scale.value) * 0.05;
+    }
+}
+// 📦 Mode toggles
+fn menu_modes(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<GameState>,
+    mut config: ResMut<GameConfig>,
+    mut companions: ResMut<Companions>,
+    mut modes_text: Query<&mut Text, With<MenuModesText>>,
+) {
+    if *state != GameState::Menu {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyQ) {
+        config.delivery_quota = !config.delivery_quota;
+    }
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        config.lives = !config.lives;
+    }
+    if keyboard.just_pressed(KeyCode::KeyV) {
+        config.vertical_movement = !config.vertical_movement;
+    }
+    if keyboard.just_pressed(KeyCode::KeyK) {
+        companions.cycle();
+    }
+
+    if let Ok(mut text) = modes_text.get_single_mut() {
+        text.sections[0].value = menu_modes_label(&config, &companions);
+    }
+}
+
+fn menu_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<GameState>,
+    mut commands: Commands,
+    menu_ui: Query<Entity, With<MenuUI>>,
+    menu_bg: Query<Entity, With<MenuBackground>>,
+    mut player: Query<&mut Visibility, With<Player>>,
+    headless: Res<Headless>,
+) {
+    if *state != GameState::Menu {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Enter) {
+        *state = GameState::Playing;
+
+        // 🧹 Remove menu UI
+        for e in menu_ui.iter() {
+            commands.entity(e).despawn_recursive();
+        }
+
+        // 🧹 Remove menu background
+        for e in menu_bg.iter() {
+            commands.entity(e).despawn_recursive();
+        }
+
+        // 👀 SHOW SANTA
+        if let Ok(mut vis) = player.get_single_mut() {
+            *vis = Visibility::Visible;
+        }
+
+        if !headless.0 {
+            println!("▶ GAME STARTED");
+        }
+    }
+}
+
+fn menu_modes_label(config: &GameConfig, companions: &Companions) -> String {
+    let on_off = |on: bool| if on { "ON" } else { "OFF" };
+    let companion = match (companions.selected, companions.unlocked().is_empty()) {
+        (Some(kind), _) => format!("{kind:?}").to_uppercase(),
+        (None, true) => "LOCKED".to_string(),
+        (None, false) => "NONE".to_string(),
+    };
+
+    format!(
+        "Q - DELIVERY QUOTA: {}\nL - LIVES & WARM REBOOTS: {}\nV - VERTICAL MOVEMENT: {}\nK - COMPANION: {}\nS - LIFETIME STATS",
+        on_off(config.delivery_quota),
+        on_off(config.lives),
+        on_off(config.vertical_movement),
+        companion
+    )
+}
+
+fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("images/title_screen.png"), // your big image
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, -10.0), // behind UI
+                scale: Vec3::splat(1.0),
+                ..default()
+            },
+            ..default()
+        },
+        MenuBackground, // 🔑 IMPORTANT
+    ));
+
+    // ⚙ Mode toggles
+    commands.spawn((
+        TextBundle::from_section(
+            menu_modes_label(&GameConfig::default(), &Companions::default()),
+            TextStyle {
+                font: asset_server.load("fonts/PixelOperator8-Bold.ttf"),
+                font_size: 16.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        MenuUI,
+        MenuModesText,
+    ));
+
+    // 📊 Lifetime stats (hidden until S)
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.85).into(),
+                ..default()
+            },
+            MenuUI,
+            StatsScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("fonts/PixelOperator8-Bold.ttf"),
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                StatsText,
+            ));
+        });
+}
+
+fn stats_screen(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<GameState>,
+    lifetime: Res<LifetimeStats>,
+    mut screen: Query<&mut Style, With<StatsScreen>>,
+    mut text: Query<&mut Text, With<StatsText>>,
+) {
+    if *state != GameState::Menu {
+        return;
+    }
+    let Ok(mut style) = screen.get_single_mut() else {
+        return;
+    };
+
+    let open = style.display != Display::None;
+    let toggle =
+        keyboard.just_pressed(KeyCode::KeyS) || (open && keyboard.just_pressed(KeyCode::Escape));
+    if !toggle {
+        return;
+    }
+
+    style.display = if open { Display::None } else { Display::Flex };
+    if let Ok(mut text) = text.get_single_mut() {
+        text.sections[0].value = stats_report(&lifetime.totals);
+    }
+}
+
+fn stats_report(totals: &Totals) -> String {
+    let minutes = |seconds: f32| format!("{}m {:02}s", seconds as u32 / 60, seconds as u32 % 60);
+    let deaths = &totals.deaths;
+
+    let mut report = format!(
+        "LIFETIME STATS\n\n\
+         RUNS            {}\n\
+         PRESENTS        {} ({} GOLDEN)\n\
+         FANS USED       {}\n\
+         OVERCLOCK TIME  {}\n\
+         TIME PLAYED     {}\n\
+         BEST SURVIVAL   {}\n\
+         BEST SCORE      {}\n\
+         AVERAGE SCORE   {}\n\n\
+         DEATHS  OVERHEAT {}  CORRUPTED {}  DELIVERY {}\n\n\
+         CRASH HEAT\n",
+        totals.runs,
+        totals.presents_collected,
+        totals.golden_collected,
+        totals.fans_used,
+        minutes(totals.overclock_seconds),
+        minutes(totals.seconds_played),
+        minutes(totals.best_seconds),
+        totals.best_score,
+        totals.average_score(),
+        deaths.overheat,
+        deaths.corrupted,
+        deaths.delivery_failed,
+    );
+
+    // 🌡 One bar per 10 °C, scaled to the busiest bucket
+    let peak = totals.crash_heat.iter().copied().max().unwrap_or(0).max(1);
+    for (i, count) in totals.crash_heat.iter().enumerate() {
+        let label = if i + 1 == HEAT_BUCKETS {
+            format!("{:>2}+", i * 10)
+        } else {
+            format!("{:>2}-{:<2}", i * 10, i * 10 + 9)
+        };
+        let bar = "#".repeat((count * 24).div_ceil(peak) as usize);
+        report.push_str(&format!("{label:<6} {bar} {count}\n"));
+    }
+
+    report.push_str("\nS / ESC - BACK");
+    report
+}
+
+fn spawn_snowflakes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<SnowSpawnTimer>,
+    state: Res<GameState>,
+    weather: Res<Weather>,
+    mut rng: ResMut<GameRng>,
+) {
+    // ❄️ No snow in menu
+    if *state != GameState::Playing {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+
+    if !timer.0.just_finished() {
+        return;
+    }
+
+    // 🌨 Blizzard: four times the flakes, falling faster
+    let blizzard = weather.intensity(WeatherKind::Blizzard);
+    let flakes = 1 + (3.0 * blizzard).round() as usize;
+
+    let rng = &mut rng.scenery;
+    for _ in 0..flakes {
+        let x = rng.gen_range(-380.0..380.0);
+        let size = rng.gen_range(2.0..4.0);
+        let speed = rng.gen_range(30.0..80.0) * (1.0 + 1.5 * blizzard);
+
+        commands.add(
+            SpawnPooled::new(PoolKind::Snowflake, Transform::from_xyz(x, 380.0, -1.0)).with((
+                Sprite {
+                    color: Color::WHITE,
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                SnowSpeed(speed),
+            )),
+        );
+    }
+}
+
+fn move_snow(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &SnowSpeed, &Pooled), With<Snowflake>>,
+    time: Res<Time>,
+    state: Res<GameState>,
+    weather: Res<Weather>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    for (entity, mut transform, speed, pooled) in query.iter_mut() {
+        if !pooled.active {
+            continue;
+        }
+
+        transform.translation.y -= speed.0 * time.delta_seconds();
+        transform.translation.x += weather.wind() * time.delta_seconds();
+
+        // ❄️ When snow reaches bottom → back to the pool
+        if transform.translation.y < -380.0 {
+            commands.add(ReleasePooled(entity));
+        }
+    }
+}
+
+/* =======================
+   WEATHER
+======================= */
+
+// Wind only pushes things sideways; keep them catchable
+fn drift(transform: &mut Transform, weather: &Weather, dt: f32) {
+    transform.translation.x = (transform.translation.x + weather.wind() * dt).clamp(-330.0, 330.0);
+}
+
+fn apply_weather_veil(
+    weather: Res<Weather>,
+    state: Res<GameState>,
+    mut veil: Query<&mut Sprite, With<WeatherVeil>>,
+) {
+    let Ok(mut sprite) = veil.get_single_mut() else {
+        return;
+    };
+
+    if *state == GameState::Menu {
+        sprite.color = Color::NONE;
+        return;
+    }
+
+    // 🌫 Whiteout for blizzards, a faint orange haze for heat waves
+    let blizzard = weather.intensity(WeatherKind::Blizzard);
+    let heat_wave = weather.intensity(WeatherKind::HeatWave);
+
+    sprite.color = if blizzard > 0.0 {
+        Color::rgba(0.85, 0.9, 1.0, 0.55 * blizzard)
+    } else if heat_wave > 0.0 {
+        Color::rgba(1.0, 0.4, 0.1, 0.12 * heat_wave)
+    } else {
+        Color::NONE
+    };
+}
+
+fn update_weather_text(
+    weather: Res<Weather>,
+    state: Res<GameState>,
+    mut text: Query<&mut Text, With<WeatherText>>,
+) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+    let section = &mut text.sections[0];
+
+    if *state != GameState::Playing {
+        section.value.clear();
+        return;
+    }
+
+    if let Some(kind) = weather.current {
+        // 📢 Big announcement first, then a countdown
+        section.value = if weather.event.elapsed_secs() < Weather::WARNING {
+            format!("{}!\n{}", kind.title(), kind.detail())
+        } else {
+            format!("{} {:.0}s", kind.title(), weather.event.remaining_secs())
+        };
+        section.style.color = kind.color();
+    } else if weather.calm.remaining_secs() < Weather::WARNING {
+        section.value = format!("{} INCOMING", weather.forecast.title());
+        section.style.color = weather.forecast.color().with_a(0.6);
+    } else {
+        section.value.clear();
+    }
+}
+
+/* =======================
+   GRINCH BOSS
+======================= */
+
+fn boss_director(
+    mut fx: Effects,
+    time: Res<Time>,
+    state: Res<GameState>,
+    mut boss: ResMut<Boss>,
+    mut grinch: Query<(Entity, &mut Transform), With<GrinchBoss>>,
+) {
+    // 🧹 Reset (or never started): nothing left on screen
+    if boss.phase == BossPhase::Dormant {
+        for (entity, _) in grinch.iter() {
+            fx.commands.entity(entity).despawn();
+        }
+    }
+
+    if *state != GameState::Playing {
+        return;
+    }
+
+    boss.timer.tick(time.delta());
+    boss.flash.tick(time.delta());
+    boss.cooldown.tick(time.delta());
+
+    match boss.phase {
+        BossPhase::Dormant => {
+            if !boss.timer.finished() {
+                return;
+            }
+
+            boss.start_encounter();
+            fx.shake.intensity = 6.0;
+            fx.notices
+                .send(Notice::new(NoticeLevel::Warn, "GRINCH.EXE DETECTED"));
+
+            // 🔊 Low alarm
+            fx.commands.spawn(AudioBundle {
+                source: fx.asset_server.load("audio/Fire.wav"),
+                settings: PlaybackSettings::DESPAWN.with_speed(0.5),
+            });
+
+            fx.commands.spawn((
+                SpriteBundle {
+                    texture: fx.asset_server.load("sprites/corrupted.png"),
+                    sprite: Sprite {
+                        color: Color::LIME_GREEN,
+                        ..default()
+                    },
+                    transform: Transform {
+                        translation: Vec3::new(0.0, Boss::OFFSCREEN_Y, 1.0),
+                        scale: Vec3::splat(1.2),
+                        ..default()
+                    },
+                    ..default()
+                },
+                GrinchBoss,
+            ));
+        }
+        BossPhase::Intro => {
+            if let Ok((_, mut transform)) = grinch.get_single_mut() {
+                let t = boss.timer.fraction();
+                transform.translation.y = Boss::OFFSCREEN_Y.lerp(Boss::ARENA_Y, t * (2.0 - t));
+            }
+
+            if boss.timer.finished() {
+                boss.phase = BossPhase::Fighting;
+                boss.timer = Timer::from_seconds(Boss::TIME_LIMIT, TimerMode::Once);
+                boss.attack = Timer::from_seconds(GRINCH_SCRIPT[0].wait, TimerMode::Once);
+            }
+        }
+        BossPhase::Fighting => {
+            if boss.health == 0 {
+                boss.phase = BossPhase::Retreating;
+                fx.shake.intensity = 6.0;
+
+                fx.commands.spawn(AudioBundle {
+                    source: fx.asset_server.load("audio/power_up.wav"),
+                    settings: PlaybackSettings::DESPAWN,
+                });
+            } else if boss.timer.finished() {
+                boss.phase = BossPhase::Retreating;
+                fx.notices
+                    .send(Notice::new(NoticeLevel::Warn, "GRINCH.EXE ESCAPED"));
+            }
+        }
+        BossPhase::Retreating => {
+            let Ok((entity, mut transform)) = grinch.get_single_mut() else {
+                return;
+            };
+
+            transform.translation.y += 200.0 * time.delta_seconds();
+
+            if transform.translation.y > Boss::OFFSCREEN_Y {
+                fx.commands.entity(entity).despawn();
+                boss.phase = BossPhase::Dormant;
+                boss.timer = Timer::from_seconds(Boss::BETWEEN_ENCOUNTERS, TimerMode::Once);
+            }
+        }
+    }
+}
+
+type BossTarget<'w, 's> =
+    Query<'w, 's, (&'static Transform, &'static Velocity), (With<Player>, Without<GrinchBoss>)>;
+
+fn boss_attacks(
+    mut commands: Commands,
+    tick: Tick,
+    data: Res<GameData>,
+    mut boss: ResMut<Boss>,
+    mut rng: ResMut<GameRng>,
+    player: BossTarget,
+    mut grinch: Query<(&mut Transform, &mut Sprite), With<GrinchBoss>>,
+) {
+    let Ok((mut transform, mut sprite)) = grinch.get_single_mut() else {
+        return;
+    };
+
+    // 💥 Red flash on every hit
+    sprite.color = if boss.flash.finished() {
+        Color::LIME_GREEN
+    } else {
+        Color::RED
+    };
+
+    if !tick.playing() || boss.phase != BossPhase::Fighting {
+        return;
+    }
+
+    // ↔ Sweep across the top of the screen
+    let t = boss.timer.elapsed_secs();
+    transform.translation.x = 260.0 * (t * 0.6).sin();
+
+    boss.attack
+        .tick(tick.time.delta().mul_f32(tick.time_scale.value));
+    if !boss.attack.finished() {
+        return;
+    }
+
+    let script = boss.script();
+    let step = &script[boss.step % script.len()];
+    boss.step += 1;
+    let next = &script[boss.step % script.len()];
+    boss.attack = Timer::from_seconds(next.wait, TimerMode::Once);
+
+    let origin = transform.translation.truncate() - Vec2::new(0.0, 40.0);
+    let fall = bit_fall(&tick.difficulty, &data);
+    let mut fire = |offset: Vec2, drift: f32| {
+        commands.add(
+            SpawnPooled::new(
+                PoolKind::CorruptedBit,
+                Transform {
+                    translation: (origin + offset).extend(0.0),
+                    scale: Vec3::splat(0.6),
+                    ..default()
+                },
+            )
+            .with(BitDrift(drift)),
+        );
+    };
+
+    match step.attack {
+        BossAttack::Fan { count, spread } => {
+            for i in 0..count {
+                let t = i as f32 / (count - 1).max(1) as f32 - 0.5;
+                let angle = (t * spread).to_radians();
+                fire(Vec2::ZERO, fall * angle.tan());
+            }
+        }
+        BossAttack::Rain { count } => {
+            for _ in 0..count {
+                let x = rng.gen_range(-120.0..120.0);
+                let y = rng.gen_range(0.0..60.0);
+                fire(Vec2::new(x, y), 0.0);
+            }
+        }
+        BossAttack::Aimed => {
+            // 🎯 Lead Santa: aim where he'll be if he keeps going like this
+            let (santa, velocity) = player.get_single().map_or(
+                (Vec2::new(0.0, MovementConfig::FLOOR_Y), Vec2::ZERO),
+                |(transform, velocity)| (transform.translation.truncate(), velocity.0),
+            );
+            // Time scale slows the bit on both axes, so it only stretches the flight
+            let ticks = ((origin.y - santa.y) / fall).max(1.0);
+            let flight = ticks / tick.time_scale.value.max(0.1) * tick.time.delta_seconds();
+            let target = (santa.x + velocity.x * flight).clamp(-320.0, 320.0);
+            fire(Vec2::ZERO, (target - origin.x) / ticks);
+        }
+    }
+}
+
+fn update_boss_bar(
+    boss: Res<Boss>,
+    state: Res<GameState>,
+    mut bar: Query<&mut Style, (With<BossBar>, Without<BossBarFill>)>,
+    mut fill: Query<&mut Style, (With<BossBarFill>, Without<BossBar>)>,
+    mut label: Query<&mut Text, With<BossLabel>>,
+) {
+    let (Ok(mut bar), Ok(mut fill), Ok(mut label)) = (
+        bar.get_single_mut(),
+        fill.get_single_mut(),
+        label.get_single_mut(),
+    ) else {
+        return;
+    };
+
+    let show = *state != GameState::Menu && (boss.is_active() || boss.is_cooling());
+    bar.display = if show { Display::Flex } else { Display::None };
+
+    let health = boss.health as f32 / boss.max_health.max(1) as f32;
+    fill.width = Val::Percent(100.0 * health);
+
+    label.sections[0].value = match boss.phase {
+        BossPhase::Intro => "WARNING: GRINCH.EXE INCOMING".to_string(),
+        BossPhase::Fighting => format!(
+            "GRINCH.EXE  {}/{}  {:.0}s",
+            boss.health,
+            boss.max_health,
+            boss.timer.remaining_secs()
+        ),
+        _ => format!(
+            "VIRUS REMOVED +{}  COOLDOWN {:.0}s",
+            Boss::BONUS,
+            boss.cooldown.remaining_secs()
+        ),
+    };
+}
+
+/* =======================
+   GRINCH ELVES
+======================= */
+
+const ELF_LANE_Y: f32 = 20.0;
+
+fn spawn_grinch_elves(
+    mut fx: Effects,
+    time: Res<Time>,
+    state: Res<GameState>,
+    difficulty: Res<Difficulty>,
+    boss: Res<Boss>,
+    mut timer: ResMut<ElfSpawnTimer>,
+    mut rng: ResMut<GameRng>,
+) {
+    if *state != GameState::Playing || boss.is_active() {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+    if !timer.0.finished() {
+        return;
+    }
+
+    // ⏱ Next one sooner as difficulty climbs
+    let gap = rng.gen_range(15.0..25.0) / difficulty.level;
+    timer.0 = Timer::from_seconds(gap.max(6.0), TimerMode::Once);
+
+    let direction = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+    let coal_in = rng.gen_range(1.5..3.0);
+
+    fx.commands.spawn((
+        SpriteBundle {
+            texture: fx.asset_server.load("sprites/santa.png"),
+            sprite: Sprite {
+                color: Color::GREEN,
+                flip_x: direction < 0.0,
+                ..default()
+            },
+            transform: Transform {
+                translation: Vec3::new(-420.0 * direction, ELF_LANE_Y, 0.5),
+                scale: Vec3::splat(0.35),
+                ..default()
+            },
+            ..default()
+        },
+        GrinchElf {
+            direction,
+            coal: Timer::from_seconds(coal_in, TimerMode::Once),
+        },
+        Collider::aabb(CollisionLayer::Thief, Vec2::new(167.0, 215.0), 0.6),
+    ));
+}
+
+fn move_grinch_elves(
+    mut commands: Commands,
+    time: Res<Time>,
+    state: Res<GameState>,
+    time_scale: Res<TimeScale>,
+    mut rng: ResMut<GameRng>,
+    mut elves: Query<(Entity, &mut Transform, &mut GrinchElf)>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    let dt = time.delta_seconds() * time_scale.value;
+
+    for (entity, mut transform, mut elf) in elves.iter_mut() {
+        transform.translation.x += elf.direction * 110.0 * dt;
+
+        // 🚶 Off the far side
+        if transform.translation.x.abs() > 450.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        elf.coal.tick(time.delta().mul_f32(time_scale.value));
+        if !elf.coal.finished() {
+            continue;
+        }
+        elf.coal = Timer::from_seconds(rng.gen_range(1.5..3.0), TimerMode::Once);
+
+        // ⚫ Only drop coal where Santa can reach it
+        if transform.translation.x.abs() > 330.0 {
+            continue;
+        }
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.12, 0.12, 0.12),
+                    custom_size: Some(Vec2::splat(22.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(transform.translation.x, ELF_LANE_Y - 20.0, 0.0),
+                ..default()
+            },
+            Coal,
+            Collider::circle(CollisionLayer::Hazard, Vec2::splat(22.0), 0.9),
+        ));
+    }
+}
+
+fn move_coal(
+    mut commands: Commands,
+    state: Res<GameState>,
+    difficulty: Res<Difficulty>,
+    time_scale: Res<TimeScale>,
+    mut coal: Query<(Entity, &mut Transform), With<Coal>>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    for (entity, mut transform) in coal.iter_mut() {
+        transform.translation.y -= 3.5 * difficulty.level * time_scale.value;
+
+        if transform.translation.y < -350.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn elf_steal_presents(
+    mut commands: Commands,
+    mut stats: ResMut<RunStats>,
+    mut overlaps: RoundOverlaps,
+    elves: Query<(), With<GrinchElf>>,
+    mut presents: Query<&mut Collider, With<Present>>,
+) {
+    let Some(overlaps) = overlaps.read() else {
+        return;
+    };
+
+    for overlap in overlaps {
+        if !elves.contains(overlap.a) {
+            continue;
+        }
+        let Ok(mut collider) = presents.get_mut(overlap.b) else {
+            continue;
+        };
+
+        // ✅ Santa may have caught it this same frame
+        if collider.layer == CollisionLayer::Ghost {
+            continue;
+        }
+        collider.layer = CollisionLayer::Ghost;
+
+        // 🎁 Not Santa's fault, so the combo survives
+        stats.presents_stolen += 1;
+        commands.add(ReleasePooled(overlap.b));
+    }
+}
+
+fn hit_coal(
+    mut fx: Effects,
+    mut run: RunTally,
+    mut overlaps: RoundOverlaps,
+    dash: Res<Dash>,
+    players: Query<(), With<Player>>,
+    mut coal: Query<(&Transform, &mut Collider), With<Coal>>,
+) {
+    let Some(overlaps) = overlaps.read() else {
+        return;
+    };
+
+    for overlap in overlaps {
+        if !players.contains(overlap.a) {
+            continue;
+        }
+        let Ok((transform, mut collider)) = coal.get_mut(overlap.b) else {
+            continue;
+        };
+        if collider.layer == CollisionLayer::Ghost {
+            continue;
+        }
+
+        // 🛡 Dashing straight through is safe
+        if dash.is_dashing() {
+            continue;
+        }
+        collider.layer = CollisionLayer::Ghost;
+
+        let (message, color) = if run.data.overloading {
+            // 🐞 Overclocked: debug it for points
+            let points = 40 * run.combo.multiplier();
+            run.data.score += points;
+            run.stats.coal_debugged += 1;
+            fx.events.send(GameEvent::CoalDebugged);
+            (format!("DEBUGGED +{points}"), Color::CYAN)
+        } else {
+            // 🔥 Otherwise it just clogs the fans
+            run.data.heat = (run.data.heat + 20.0).clamp(0.0, 100.0);
+            fx.shake.intensity = 4.0;
+            ("+20 HEAT".to_string(), Color::ORANGE_RED)
+        };
+
+        fx.commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    message,
+                    TextStyle {
+                        font: fx.asset_server.load("fonts/PixelOperator8-Bold.ttf"),
+                        font_size: 16.0,
+                        color,
+                    },
+                ),
+                transform: Transform::from_translation(
+                    transform.translation.truncate().extend(5.0),
+                ),
+                ..default()
+            },
+            ScorePopup {
+                timer: Timer::from_seconds(0.8, TimerMode::Once),
+            },
+        ));
+
+        fx.commands.entity(overlap.b).despawn();
+    }
+}
+
+/* =======================
+   COMPANIONS
+======================= */
+
+const COMPANION_MAX_SPEED: f32 = 420.0;
+const COMPANION_MAX_FORCE: f32 = 1800.0;
+const COMPANION_ARRIVE_RADIUS: f32 = 80.0;
+const REINDEER_SEEK_RADIUS: f32 = 120.0;
+const REINDEER_LEASH: f32 = 200.0;
+// 🖼 sprites/companions.png: two 64 px frames each, reindeer then elf
+const COMPANION_FRAME: f32 = 64.0;
+
+impl CompanionKind {
+    fn frame(self, step: usize) -> Rect {
+        let index = match self {
+            CompanionKind::Reindeer => 0,
+            CompanionKind::Elf => 2,
+        } + step % 2;
+        let left = index as f32 * COMPANION_FRAME;
+        Rect::new(left, 0.0, left + COMPANION_FRAME, COMPANION_FRAME)
+    }
+}
+
+fn unlock_companions(
+    state: Res<GameState>,
+    stats: Res<RunStats>,
+    difficulty: Res<Difficulty>,
+    mut companions: ResMut<Companions>,
+    mut notices: EventWriter<Notice>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    if !companions.reindeer_unlocked && stats.presents_collected >= Companions::REINDEER_PRESENTS {
+        companions.unlock(CompanionKind::Reindeer);
+        notices.send(Notice::new(NoticeLevel::Unlock, "COMPANION: REINDEER"));
+    }
+    if !companions.elf_unlocked && difficulty.time_alive >= Companions::ELF_SECONDS {
+        companions.unlock(CompanionKind::Elf);
+        notices.send(Notice::new(NoticeLevel::Unlock, "COMPANION: ELF"));
+    }
+}
+
+// Keeps exactly the selected companion on screen while playing
+fn sync_companion(
+    mut commands: Commands,
+    state: Res<GameState>,
+    companions: Res<Companions>,
+    asset_server: Res<AssetServer>,
+    player: Query<&Transform, With<Player>>,
+    existing: Query<(Entity, &Companion)>,
+) {
+    let wanted = companions.selected.filter(|_| *state != GameState::Menu);
+
+    let mut present = false;
+    for (entity, companion) in existing.iter() {
+        if Some(companion.kind) == wanted {
+            present = true;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let (Some(kind), false, Ok(player)) = (wanted, present, player.get_single()) else {
+        return;
+    };
+
+    let layer = match kind {
+        CompanionKind::Reindeer => CollisionLayer::Collector,
+        CompanionKind::Elf => CollisionLayer::Shield,
+    };
+
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/companions.png"),
+            sprite: Sprite {
+                rect: Some(kind.frame(0)),
+                custom_size: Some(Vec2::splat(COMPANION_FRAME)),
+                ..default()
+            },
+            transform: Transform {
+                translation: player.translation.truncate().extend(0.8),
+                scale: Vec3::splat(0.9),
+                ..default()
+            },
+            ..default()
+        },
+        Companion {
+            kind,
+            velocity: Vec2::ZERO,
+        },
+        // Pickup / block radius ≈ 40 px around the companion itself
+        Collider::circle(layer, Vec2::splat(COMPANION_FRAME), 1.4),
+    ));
+}
+
+// Arrive steering: full speed far away, easing off inside the arrive radius
+fn steer(position: Vec2, velocity: Vec2, target: Vec2, dt: f32) -> Vec2 {
+    let to_target = target - position;
+    let ease = (to_target.length() / COMPANION_ARRIVE_RADIUS).min(1.0);
+    let desired = to_target.normalize_or_zero() * COMPANION_MAX_SPEED * ease;
+    let force = (desired - velocity).clamp_length_max(COMPANION_MAX_FORCE);
+
+    (velocity + force * dt).clamp_length_max(COMPANION_MAX_SPEED)
+}
+
+type CompanionTargets<'w, 's> =
+    Query<'w, 's, (&'static Transform, &'static Pooled), (With<Present>, Without<Companion>)>;
+
+// ⏱ Fixed timestep, so the same inputs always give the same path
+fn steer_companions(
+    time: Res<Time>,
+    state: Res<GameState>,
+    dash: Res<Dash>,
+    player: Query<&Transform, (With<Player>, Without<Companion>)>,
+    presents: CompanionTargets,
+    mut companions: Query<(&mut Transform, &mut Companion)>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+    let santa = player.translation.truncate();
+    let dt = time.delta_seconds();
+
+    for (mut transform, mut companion) in companions.iter_mut() {
+        let position = transform.translation.truncate();
+
+        let target = match companion.kind {
+            CompanionKind::Reindeer => {
+                // 🎁 Nearest reachable present, else trot behind Santa
+                let nearest = presents
+                    .iter()
+                    .filter(|(_, pooled)| pooled.active)
+                    .map(|(present, _)| present.translation.truncate())
+                    .filter(|p| p.distance(position) < REINDEER_SEEK_RADIUS)
+                    .filter(|p| p.distance(santa) < REINDEER_LEASH)
+                    .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)));
+
+                nearest.unwrap_or(santa + Vec2::new(-70.0 * dash.facing, 40.0))
+            }
+            // 🛡 Right above Santa, where the bits come from
+            CompanionKind::Elf => santa + Vec2::new(0.0, 90.0),
+        };
+
+        companion.velocity = steer(position, companion.velocity, target, dt);
+        let next = position + companion.velocity * dt;
+        transform.translation.x = next.x;
+        transform.translation.y = next.y;
+    }
+}
+
+fn animate_companions(
+    time: Res<Time>,
+    companions: Res<Companions>,
+    mut query: Query<(&mut Transform, &mut Sprite, &Companion)>,
+) {
+    for (mut transform, mut sprite, companion) in query.iter_mut() {
+        // 🏃 Face the way we're going, wobble while moving
+        if companion.velocity.x.abs() > 10.0 {
+            sprite.flip_x = companion.velocity.x < 0.0;
+        }
+        let pace = (companion.velocity.length() / COMPANION_MAX_SPEED).min(1.0);
+        let wobble = (time.elapsed_seconds() * 14.0).sin() * 0.15 * pace;
+        transform.rotation = Quat::from_rotation_z(wobble);
+
+        // 🦌 Trot (or bob) while moving, stand on the first frame at rest
+        let step = if pace > 0.05 {
+            (time.elapsed_seconds() * 8.0) as usize
+        } else {
+            0
+        };
+        sprite.rect = Some(companion.kind.frame(step));
+
+        // 💤 The elf fades while its shield recharges
+        if companion.kind == CompanionKind::Elf {
+            let ready = companions.shield.finished();
+            sprite.color.set_a(if ready { 1.0 } else { 0.45 });
+        }
+    }
+}
+
+fn companion_shield(
+    mut fx: Effects,
+    time: Res<Time>,
+    mut companions: ResMut<Companions>,
+    mut overlaps: RoundOverlaps,
+    elves: Query<&Companion>,
+    mut bits: Query<&mut Collider, With<CorruptedBit>>,
+) {
+    let Some(overlaps) = overlaps.read() else {
+        return;
+    };
+
+    companions.shield.tick(time.delta());
+    companions.unlocked_flash.tick(time.delta());
+
+    for overlap in overlaps {
+        if !companions.shield.finished() {
+            continue;
+        }
+        if !matches!(elves.get(overlap.a), Ok(c) if c.kind == CompanionKind::Elf) {
+            continue;
+        }
+        let Ok(mut collider) = bits.get_mut(overlap.b) else {
+            continue;
+        };
+
+        // 🛡 One bit, then a minute to recharge
+        collider.layer = CollisionLayer::Ghost;
+        fx.commands.add(ReleasePooled(overlap.b));
+        companions.shield.reset();
+        fx.notices
+            .send(Notice::new(NoticeLevel::Ok, "ELF FIREWALL BLOCKED A BIT"));
+    }
+}
+
+fn update_companion_text(
+    companions: Res<Companions>,
+    state: Res<GameState>,
+    mut text: Query<&mut Text, With<CompanionText>>,
+) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+    let section = &mut text.sections[0];
+
+    let flash = companions
+        .last_unlocked
+        .filter(|_| !companions.unlocked_flash.finished());
+
+    section.value = match (flash, companions.selected) {
+        _ if *state == GameState::Menu => String::new(),
+        (Some(kind), _) => format!("{} UNLOCKED!", format!("{kind:?}").to_uppercase()),
+        (None, Some(CompanionKind::Reindeer)) => "REINDEER: FOLLOWING".to_string(),
+        (None, Some(CompanionKind::Elf)) if companions.shield.finished() => {
+            "ELF SHIELD: READY".to_string()
+        }
+        (None, Some(CompanionKind::Elf)) => {
+            format!("ELF SHIELD: {:.0}s", companions.shield.remaining_secs())
+        }
+        (None, None) => String::new(),
+    };
+}
+
+/* =======================
+   MISSIONS
+======================= */
+
+// Three fresh missions at the start of every run (reset_round empties them)
+fn assign_missions(
+    state: Res<GameState>,
+    book: Res<MissionBook>,
+    mut missions: ResMut<Missions>,
+    mut rng: ResMut<GameRng>,
+    mut notices: EventWriter<Notice>,
+) {
+    if *state != GameState::Playing || !missions.active.is_empty() {
+        return;
+    }
+
+    let mut pool: Vec<&MissionDef> = book.0.iter().collect();
+    while missions.active.len() < MissionBook::PER_RUN && !pool.is_empty() {
+        let def = pool.swap_remove(rng.gen_range(0..pool.len()));
+        notices.send(Notice::new(
+            NoticeLevel::Info,
+            format!("MISSION: {}", def.description),
+        ));
+
+        missions.active.push(Mission {
+            def: def.clone(),
+            progress: 0.0,
+            done: false,
+        });
+    }
+}
+
+fn track_missions(
+    tick: Tick,
+    mut run: RunTally,
+    mut profile: Profile,
+    mut missions: ResMut<Missions>,
+    mut events: EventReader<GameEvent>,
+    mut notices: EventWriter<Notice>,
+) {
+    if !tick.playing() {
+        events.clear();
+        return;
+    }
+
+    let missions = &mut *missions;
+    missions.since_fan += tick.time.delta_seconds();
+
+    let events: Vec<GameEvent> = events.read().copied().collect();
+    if events.iter().any(|e| matches!(e, GameEvent::FanCollected)) {
+        missions.since_fan = 0.0;
+    }
+
+    for mission in missions.active.iter_mut().filter(|m| !m.done) {
+        for event in &events {
+            mission.progress += match (mission.def.goal, *event) {
+                (
+                    MissionGoal::CollectWhileHot { heat: min, .. },
+                    GameEvent::PresentCaught { heat, .. },
+                ) if heat >= min => 1.0,
+                (
+                    MissionGoal::CatchGolden { .. },
+                    GameEvent::PresentCaught { golden: true, .. },
+                )
+                | (MissionGoal::DebugCoal { .. }, GameEvent::CoalDebugged)
+                | (MissionGoal::DefeatBoss, GameEvent::BossDefeated) => 1.0,
+                _ => 0.0,
+            };
+        }
+
+        // ⏱ Running totals are tracked as "best so far"
+        match mission.def.goal {
+            MissionGoal::SurviveWithoutFan { .. } => {
+                mission.progress = mission.progress.max(missions.since_fan);
+            }
+            MissionGoal::ReachCombo { .. } => {
+                mission.progress = mission.progress.max(run.combo.count as f32);
+            }
+            MissionGoal::Survive { .. } => mission.progress = tick.difficulty.time_alive,
+            _ => {}
+        }
+
+        if mission.progress >= mission.def.goal.target() {
+            mission.done = true;
+            run.stats.missions_completed += 1;
+            match mission.def.reward {
+                MissionReward::Score(points) => run.data.score += points,
+                MissionReward::Cookies(count) => {
+                    profile.wallet.cookies += count;
+                    if !profile.headless.0 {
+                        profile.wallet.save();
+                    }
+                }
+            }
+            notices.send(Notice::new(
+                NoticeLevel::Ok,
+                format!(
+                    "MISSION {} COMPLETE ({})",
+                    mission.def.id,
+                    mission.def.reward.label()
+                ),
+            ));
+        }
+    }
+}
+
+fn update_mission_text(
+    state: Res<GameState>,
+    missions: Res<Missions>,
+    mut text: Query<&mut Text, With<MissionText>>,
+) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+
+    text.sections[0].value = if *state == GameState::Playing {
+        missions
+            .active
+            .iter()
+            .map(|m| format!("{}  {}", m.status(), m.def.reward.label()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        String::new()
+    };
+}
+
+/* =======================
+   ACHIEVEMENTS
+======================= */
+
+// Crash report: add this run to the saved lifetime totals
+fn record_lifetime_stats(
+    data: Res<GameData>,
+    stats: Res<RunStats>,
+    difficulty: Res<Difficulty>,
+    headless: Res<Headless>,
+    mut lifetime: ResMut<LifetimeStats>,
+    mut events: EventReader<GameEvent>,
+) {
+    if !events.read().any(|e| matches!(e, GameEvent::Crashed(_))) {
+        return;
+    }
+
+    let summary = RunSummary::capture(&stats, &data, &difficulty);
+    let run = Totals::from_run(&summary);
+    lifetime.totals = lifetime.totals.merged(&run, &lifetime.folded);
+    lifetime.totals.record_crash(&summary);
+    lifetime.folded = run;
+    if !headless.0 {
+        lifetime.save();
+    }
+}
+
+fn evaluate_achievements(
+    tick: Tick,
+    tally: RunTally,
+    mut profile: Profile,
+    mut events: EventReader<GameEvent>,
+    mut notices: EventWriter<Notice>,
+    mut since_check: Local<f32>,
+    mut crash_checked: Local<bool>,
+) {
+    // 🔔 Re-check on gameplay events, once a second (timers) and on the crash report
+    let had_events = events.read().count() > 0;
+    *since_check += tick.time.delta_seconds();
+
+    let due = match *tick.state {
+        GameState::Playing => {
+            *crash_checked = false;
+            had_events || *since_check >= 1.0
+        }
+        GameState::Crashed => !std::mem::replace(&mut *crash_checked, true),
+        GameState::Menu => false,
+    };
+    if !due {
+        return;
+    }
+    *since_check = 0.0;
+
+    let run = RunSummary::capture(&tally.stats, &tally.data, &tick.difficulty);
+    let totals = profile
+        .lifetime
+        .totals
+        .merged(&Totals::from_run(&run), &profile.lifetime.folded);
+
+    let mut unlocked_any = false;
+    for def in ACHIEVEMENTS {
+        if profile.achievements.is_unlocked(def.id) || !def.condition.met(&run, &totals) {
+            continue;
+        }
+
+        profile.achievements.unlocked.push(def.id.to_string());
+        unlocked_any = true;
+        notices.send(Notice::new(
+            NoticeLevel::Unlock,
+            format!("ACHIEVEMENT: {} - {}", def.name, def.description),
+        ));
+    }
+
+    if unlocked_any && !profile.headless.0 {
+        profile.achievements.save();
+    }
+}
+
+fn fade_messages(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut messages: Query<(
+        Entity,
+        &mut MessageText,
+        &mut Text,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    for (entity, mut message, mut text, background) in messages.iter_mut() {
+        message.alpha -= time.delta_seconds();
+
+        if message.alpha <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let alpha = message.alpha.min(1.0);
+        for section in text.sections.iter_mut() {
+            section.style.color.set_a(alpha);
+        }
+        if let Some(mut background) = background {
+            background.0.set_a(alpha * LOG_BACKDROP_ALPHA);
+        }
+    }
+}
+
+/* =======================
+   RUN EXPORT
+======================= */
+
+#[derive(Clone, Copy, Serialize)]
+struct DifficultyMark {
+    seconds: f32,
+    level: f32,
+}
+
+// Sampled while playing, written out by `export_run`
+#[derive(Resource, Default)]
+struct RunTelemetry {
+    // Heat at the start of each second alive
+    heat: Vec<f32>,
+    // When the difficulty passed each DIFFICULTY_STEP
+    difficulty: Vec<DifficultyMark>,
+}
+
+impl RunTelemetry {
+    const DIFFICULTY_STEP: f32 = 0.1;
+    const JSON_FILE: &'static str = "runs.jsonl";
+    const CSV_FILE: &'static str = "runs.csv";
+}
+
+#[derive(Serialize)]
+struct RunModes {
+    delivery_quota: bool,
+    lives: bool,
+    vertical_movement: bool,
+}
+
+// One JSON line per crash report
+#[derive(Serialize)]
+struct RunRecord<'a> {
+    seed: u64,
+    fixed_seed: bool,
+    modes: RunModes,
+    config_hash: String,
+    companion: Option<CompanionKind>,
+    summary: &'a RunSummary,
+    heat_samples: &'a [f32],
+    difficulty_timeline: &'a [DifficultyMark],
+}
+
+// CSV wants flat rows: the summary plus a few columns derived from the samples
+#[derive(Serialize)]
+struct RunRow {
+    seed: u64,
+    delivery_quota: bool,
+    lives: bool,
+    vertical_movement: bool,
+    config_hash: String,
+    seconds: f32,
+    score: u32,
+    crash_cause: Option<CrashCause>,
+    crash_heat: f32,
+    mean_heat: f32,
+    presents_collected: u32,
+    presents_missed: u32,
+    presents_stolen: u32,
+    golden_collected: u32,
+    fans_used: u32,
+    coal_debugged: u32,
+    bosses_defeated: u32,
+    missions_completed: u32,
+    max_combo: u32,
+    overclock_seconds: f32,
+    continues_used: u32,
+    seconds_to_level_2: Option<f32>,
+}
+
+impl RunRow {
+    fn new(record: &RunRecord) -> Self {
+        let run = record.summary;
+        let samples = record.heat_samples;
+        let mean_heat = samples.iter().sum::<f32>() / samples.len().max(1) as f32;
+
+        Self {
+            seed: record.seed,
+            delivery_quota: record.modes.delivery_quota,
+            lives: record.modes.lives,
+            vertical_movement: record.modes.vertical_movement,
+            config_hash: record.config_hash.clone(),
+            seconds: run.seconds,
+            score: run.score,
+            crash_cause: run.crash_cause,
+            crash_heat: run.crash_heat,
+            mean_heat,
+            presents_collected: run.presents_collected,
+            presents_missed: run.presents_missed,
+            presents_stolen: run.presents_stolen,
+            golden_collected: run.golden_collected,
+            fans_used: run.fans_used,
+            coal_debugged: run.coal_debugged,
+            bosses_defeated: run.bosses_defeated,
+            missions_completed: run.missions_completed,
+            max_combo: run.max_combo,
+            overclock_seconds: run.overclock_seconds,
+            continues_used: run.continues_used,
+            seconds_to_level_2: record
+                .difficulty_timeline
+                .iter()
+                .find(|mark| mark.level >= 2.0 - 1e-3)
+                .map(|mark| mark.seconds),
+        }
+    }
+}
+
+fn sample_telemetry(
+    state: Res<GameState>,
+    data: Res<GameData>,
+    difficulty: Res<Difficulty>,
+    mut telemetry: ResMut<RunTelemetry>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    // ♻ A warm reboot rewinds the clock; forget what it undid
+    let second = difficulty.time_alive as usize;
+    telemetry.heat.truncate(second + 1);
+    telemetry
+        .difficulty
+        .retain(|mark| mark.seconds <= difficulty.time_alive);
+
+    while telemetry.heat.len() <= second {
+        telemetry.heat.push(data.heat);
+    }
+
+    loop {
+        let next = 1.0 + RunTelemetry::DIFFICULTY_STEP * (telemetry.difficulty.len() + 1) as f32;
+        if difficulty.level < next - 1e-3 {
+            break;
+        }
+        telemetry.difficulty.push(DifficultyMark {
+            seconds: difficulty.time_alive,
+            level: next,
+        });
+    }
+}
+
+// 🏁 A run is over once its crash can't be undone: no warm reboot left to
+// fall back on, or the player rebooted with R instead. Runs before R resets it.
+#[derive(SystemParam)]
+struct RunEnd<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    state: Res<'w, GameState>,
+    config: Res<'w, GameConfig>,
+    stats: Res<'w, RunStats>,
+    events: EventReader<'w, 's, GameEvent>,
+}
+
+impl RunEnd<'_, '_> {
+    fn just_ended(&mut self) -> bool {
+        let crashed = self
+            .events
+            .read()
+            .any(|e| matches!(e, GameEvent::Crashed(_)));
+
+        if self.config.lives && self.stats.reboots_left() > 0 {
+            *self.state == GameState::Crashed && self.keyboard.just_pressed(KeyCode::KeyR)
+        } else {
+            crashed
+        }
+    }
+}
+
+fn export_run(
+    rng: Res<GameRng>,
+    data: Res<GameData>,
+    difficulty: Res<Difficulty>,
+    companions: Res<Companions>,
+    telemetry: Res<RunTelemetry>,
+    mut run: RunEnd,
+) {
+    if !run.just_ended() || !run.config.export_runs {
+        return;
+    }
+
+    let (config, stats) = (&run.config, &run.stats);
+    let summary = RunSummary::capture(stats, &data, &difficulty);
+    let record = RunRecord {
+        seed: rng.seed,
+        fixed_seed: rng.fixed.is_some(),
+        modes: RunModes {
+            delivery_quota: config.delivery_quota,
+            lives: config.lives,
+            vertical_movement: config.vertical_movement,
+        },
+        config_hash: config.tuning_hash(),
+        companion: companions.selected,
+        summary: &summary,
+        heat_samples: &telemetry.heat,
+        difficulty_timeline: &telemetry.difficulty,
+    };
+
+    let result = analytics::append_json_line(RunTelemetry::JSON_FILE, &record)
+        .and_then(|_| analytics::append_csv_row(RunTelemetry::CSV_FILE, &RunRow::new(&record)));
+    if let Err(err) = result {
+        println!("⚠ couldn't export run: {err}");
+    }
+}
+
+/* =======================
+   SYSTEM LOG
+======================= */
+
+const LOG_BACKDROP_ALPHA: f32 = 0.6;
+
+// Echoes each notice to the console and queues it for the panel
+fn queue_notices(
+    headless: Res<Headless>,
+    mut log: ResMut<SystemLog>,
+    mut notices: EventReader<Notice>,
+) {
+    for notice in notices.read() {
+        if !headless.0 {
+            println!("{} {}", notice.level.tag(), notice.text);
+        }
+        log.push(notice.clone());
+    }
+}
+
+// Prints a queued notice every LINE_INTERVAL, like a terminal scrolling
+fn show_notices(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut log: ResMut<SystemLog>,
+    mut since_line: Local<f32>,
+    panel: Query<Entity, With<LogPanel>>,
+    lines: Query<(Entity, &LogLine, &MessageText)>,
+) {
+    *since_line += time.delta_seconds();
+    if *since_line < SystemLog::LINE_INTERVAL {
+        return;
+    }
+    let Ok(panel) = panel.get_single() else {
+        return;
+    };
+    let Some(notice) = log.pop() else {
+        return;
+    };
+
+    // 📜 Full → drop the least important line, oldest first
+    if lines.iter().count() >= SystemLog::MAX_LINES {
+        let victim = lines
+            .iter()
+            .filter(|(_, line, _)| line.level <= notice.level)
+            .min_by(|(_, a, a_text), (_, b, b_text)| {
+                a.level
+                    .cmp(&b.level)
+                    .then(a_text.alpha.total_cmp(&b_text.alpha))
+            });
+
+        let Some((victim, _, _)) = victim else {
+            log.requeue(notice);
+            return;
+        };
+        commands.entity(victim).despawn_recursive();
+    }
+
+    let font = asset_server.load("fonts/PixelOperator8-Bold.ttf");
+    let mut backdrop = Color::rgb(0.02, 0.04, 0.02);
+    backdrop.set_a(LOG_BACKDROP_ALPHA);
+
+    commands.entity(panel).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_sections([
+                TextSection::new(
+                    format!("{} ", notice.level.tag()),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color: notice.level.color(),
+                    },
+                ),
+                TextSection::new(
+                    notice.text,
+                    TextStyle {
+                        font,
+                        font_size: 12.0,
+                        color: Color::rgb(0.85, 0.95, 0.85),
+                    },
+                ),
+            ])
+            .with_style(Style {
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                ..default()
+            })
+            .with_background_color(backdrop),
+            // +1: a second of fade after the hold
+            MessageText {
+                alpha: notice.lifetime + 1.0,
+            },
+            LogLine {
+                level: notice.level,
+            },
+        ));
+    });
+    *since_line = 0.0;
+}
+
+// Turns gameplay events into log lines so their systems stay UI-free
+fn log_gameplay_events(mut events: EventReader<GameEvent>, mut notices: EventWriter<Notice>) {
+    for event in events.read() {
+        match event {
+            GameEvent::FanCollected => {
+                notices.send(Notice::new(NoticeLevel::Ok, "FAN ONLINE").lasting(2.0));
+            }
+            GameEvent::BossDefeated => {
+                notices.send(Notice::new(
+                    NoticeLevel::Ok,
+                    format!("GRINCH.EXE QUARANTINED (+{})", Boss::BONUS),
+                ));
+            }
+            GameEvent::Crashed(cause) => {
+                notices.send(Notice::new(
+                    NoticeLevel::Fail,
+                    format!("{}: {}", cause.title(), cause.detail()),
+                ));
+            }
+            GameEvent::PresentCaught { .. } | GameEvent::CoalDebugged => {}
+        }
+    }
+}
+
+/* =======================
+   ENTITY POOLS
+======================= */
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum PoolKind {
+    Snowflake,
+    Present,
+    Fan,
+    CorruptedBit,
+}
+
+impl PoolKind {
+    const ALL: [PoolKind; 4] = [
+        PoolKind::Snowflake,
+        PoolKind::Present,
+        PoolKind::Fan,
+        PoolKind::CorruptedBit,
+    ];
+
+    fn capacity(self, config: &PoolConfig) -> usize {
+        match self {
+            PoolKind::Snowflake => config.snowflake,
+            PoolKind::Present => config.present,
+            PoolKind::Fan => config.fan,
+            PoolKind::CorruptedBit => config.corrupted_bit,
+        }
+    }
+
+    fn layer(self) -> CollisionLayer {
+        match self {
+            PoolKind::Present | PoolKind::Fan => CollisionLayer::Pickup,
+            PoolKind::CorruptedBit => CollisionLayer::Hazard,
+            PoolKind::Snowflake => CollisionLayer::Ghost,
+        }
+    }
+}
+
+// Every pooled entity keeps the same components for life, so recycling it
+// never moves it between archetypes. Inactive ones are hidden, parked below
+// the screen and on the ghost layer; gameplay systems skip `!active`.
+#[derive(Component)]
+struct Pooled {
+    kind: PoolKind,
+    active: bool,
+}
+
+#[derive(Default, Clone, Copy)]
+struct PoolStats {
+    in_use: usize,
+    peak: usize,
+    recycled: usize,
+    overflow: usize,
+}
+
+#[derive(Resource, Default)]
+struct EntityPools {
+    free: HashMap<PoolKind, Vec<Entity>>,
+    stats: HashMap<PoolKind, PoolStats>,
+}
+
+const POOL_PARK: Vec3 = Vec3::new(0.0, -1000.0, 0.0);
+
+fn spawn_pooled(world: &mut World, kind: PoolKind) -> Entity {
+    let asset_server = world.resource::<AssetServer>().clone();
+    let parked = SpriteBundle {
+        transform: Transform::from_translation(POOL_PARK),
+        visibility: Visibility::Hidden,
+        ..default()
+    };
+    let pooled = Pooled {
+        kind,
+        active: false,
+    };
+
+    match kind {
+        PoolKind::Snowflake => world
+            .spawn((parked, Snowflake, SnowSpeed(0.0), pooled))
+            .id(),
+        PoolKind::Present => world
+            .spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/present.png"),
+                    ..parked
+                },
+                Present { golden: false },
+                Collider::circle(CollisionLayer::Ghost, Vec2::new(194.0, 209.0), 0.35),
+                pooled,
+            ))
+            .id(),
+        PoolKind::Fan => world
+            .spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/cooling.png"),
+                    ..parked
+                },
+                Fan,
+                Collider::circle(CollisionLayer::Ghost, Vec2::new(207.0, 209.0), 0.3),
+                pooled,
+            ))
+            .id(),
+        PoolKind::CorruptedBit => world
+            .spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/corrupted.png"),
+                    ..parked
+                },
+                CorruptedBit,
+                BitDrift::default(),
+                // 😈 Small core so near-misses feel fair
+                Collider::circle(CollisionLayer::Ghost, Vec2::new(235.0, 212.0), 0.15),
+                pooled,
+            ))
+            .id(),
+    }
+}
+
+fn prewarm_pools(world: &mut World) {
+    for kind in PoolKind::ALL {
+        let capacity = kind.capacity(&world.resource::<GameConfig>().pools);
+        let entities: Vec<Entity> = (0..capacity).map(|_| spawn_pooled(world, kind)).collect();
+
+        world
+            .resource_mut::<EntityPools>()
+            .free
+            .insert(kind, entities);
+    }
+}
+
+// Take a hidden entity (or spawn one if the pool ran dry) and put it in play.
+// `extra` overwrites per-spawn components the entity already has.
+struct SpawnPooled<B: Bundle> {
+    kind: PoolKind,
+    transform: Transform,
+    extra: B,
+}
+
+impl SpawnPooled<()> {
+    fn new(kind: PoolKind, transform: Transform) -> Self {
+        Self {
+            kind,
+            transform,
+            extra: (),
+        }
+    }
+
+    fn with<B: Bundle>(self, extra: B) -> SpawnPooled<B> {
+        SpawnPooled {
+            kind: self.kind,
+            transform: self.transform,
+            extra,
+        }
+    }
+}
+
+impl<B: Bundle> Command for SpawnPooled<B> {
+    fn apply(self, world: &mut World) {
+        let mut pools = world.resource_mut::<EntityPools>();
+        let reused = pools.free.entry(self.kind).or_default().pop();
+
+        let stats = pools.stats.entry(self.kind).or_default();
+        stats.in_use += 1;
+        stats.peak = stats.peak.max(stats.in_use);
+        if reused.is_some() {
+            stats.recycled += 1;
+        } else {
+            stats.overflow += 1;
+        }
+
+        let entity = reused.unwrap_or_else(|| spawn_pooled(world, self.kind));
+        let mut entity = world.entity_mut(entity);
+
+        *entity.get_mut::<Transform>().unwrap() = self.transform;
+        *entity.get_mut::<Visibility>().unwrap() = Visibility::Inherited;
+        entity.get_mut::<Pooled>().unwrap().active = true;
+        if let Some(mut collider) = entity.get_mut::<Collider>() {
+            collider.layer = self.kind.layer();
+        }
+        entity.insert(self.extra);
+    }
+}
+
+// Hide a pooled entity and hand it back. Anything else is just despawned.
+struct ReleasePooled(Entity);
+
+impl Command for ReleasePooled {
+    fn apply(self, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(self.0) else {
+            return;
+        };
+
+        let Some(mut pooled) = entity.get_mut::<Pooled>() else {
+            entity.despawn_recursive();
+            return;
+        };
+
+        // 🔒 Released twice in one frame (e.g. caught + missed)
+        if !pooled.active {
+            return;
+        }
+        pooled.active = false;
+        let kind = pooled.kind;
+
+        *entity.get_mut::<Visibility>().unwrap() = Visibility::Hidden;
+        entity.get_mut::<Transform>().unwrap().translation = POOL_PARK;
+        if let Some(mut collider) = entity.get_mut::<Collider>() {
+            collider.layer = CollisionLayer::Ghost;
+        }
+
+        let capacity = kind.capacity(&world.resource::<GameConfig>().pools);
+        let mut pools = world.resource_mut::<EntityPools>();
+        let stats = pools.stats.entry(kind).or_default();
+        stats.in_use = stats.in_use.saturating_sub(1);
+
+        let free = pools.free.entry(kind).or_default();
+        if free.len() < capacity {
+            free.push(self.0);
+        } else {
+            world.despawn(self.0);
+        }
+    }
+}
+
+fn update_debug_text(
+    debug: Res<DebugOverlay>,
+    pools: Res<EntityPools>,
+    config: Res<GameConfig>,
+    heatmap: Res<Heatmap>,
+    mut text: Query<&mut Text, With<DebugText>>,
+) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+
+    if !debug.0 {
+        text.sections[0].value.clear();
+        return;
+    }
+
+    // 📊 in use / pool size, peak, reuses, overflow spawns
+    let mut lines = vec!["POOL     USE  PEAK   REUSE  OVER".to_string()];
+    for kind in PoolKind::ALL {
+        let stats = pools.stats.get(&kind).copied().unwrap_or_default();
+        lines.push(format!(
+            "{:<8} {:>3}/{:<3} {:>4} {:>7} {:>5}",
+            format!("{kind:?}")
+                .to_uppercase()
+                .chars()
+                .take(8)
+                .collect::<String>(),
+            stats.in_use,
+            kind.capacity(&config.pools),
+            stats.peak,
+            stats.recycled,
+            stats.overflow
+        ));
+    }
+
+    // 🗺 Heatmap legend; edge = beyond ±EDGE_X
+    lines.push(format!("\nHEATMAP ({} RUNS)  EDGE%", heatmap.runs));
+    for channel in HeatmapChannel::ALL {
+        lines.push(format!(
+            "{:<9} {:>6} {:>5.0}",
+            channel.label(),
+            heatmap.bins(channel).iter().sum::<u32>(),
+            heatmap.edge_share(channel) * 100.0
+        ));
+    }
+    text.sections[0].value = lines.join("\n");
+}
+
+/* =======================
+   COLLISION
+======================= */
+
+type ColliderQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static Collider,
+        Option<&'static Handle<Image>>,
+        Option<&'static Sprite>,
+    ),
+>;
+
+fn hitbox(
+    transform: &Transform,
+    collider: &Collider,
+    texture: Option<&Handle<Image>>,
+    sprite: Option<&Sprite>,
+    images: &Assets<Image>,
+) -> Hitbox {
+    // 🖼 custom size → loaded texture → PNG size we expect
+    let size = sprite
+        .and_then(|sprite| sprite.custom_size)
+        .or_else(|| {
+            texture
+                .and_then(|h| images.get(h))
+                .map(|image| image.size_f32())
+        })
+        .unwrap_or(collider.nominal_size);
+
+    Hitbox {
+        center: transform.translation.truncate(),
+        half: size * transform.scale.truncate().abs() * collider.fit / 2.0,
+        shape: collider.shape,
+    }
+}
+
+fn rebuild_broadphase(
+    state: Res<GameState>,
+    images: Res<Assets<Image>>,
+    colliders: ColliderQuery,
+    mut broadphase: ResMut<Broadphase>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    // 👻 Ghosts never interact, so keep them out of the grid
+    broadphase.0.rebuild(
+        colliders
+            .iter()
+            .filter(|(_, _, collider, _, _)| collider.layer != CollisionLayer::Ghost)
+            .map(|(entity, transform, collider, texture, sprite)| {
+                (
+                    hitbox(transform, collider, texture, sprite, &images),
+                    (entity, collider.layer),
+                )
+            }),
+    );
+}
+
+fn detect_collisions(
+    state: Res<GameState>,
+    images: Res<Assets<Image>>,
+    colliders: ColliderQuery,
+    broadphase: Res<Broadphase>,
+    mut overlaps: EventWriter<Overlap>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    // 💥 Every overlapping pair, not just the first one
+    for (a, transform, collider, texture, sprite) in colliders.iter() {
+        if !collider.layer.is_active() {
+            continue;
+        }
+
+        let area = hitbox(transform, collider, texture, sprite, &images);
+
+        for (b, b_layer) in broadphase.0.overlapping(&area) {
+            if a != *b && collider.layer.interacts_with(*b_layer) {
+                overlaps.send(Overlap { a, b: *b });
+            }
+        }
+    }
+}
+
+fn toggle_debug_overlay(keyboard: Res<ButtonInput<KeyCode>>, mut debug: ResMut<DebugOverlay>) {
+    if keyboard.just_pressed(KeyCode::F3) {
+        debug.0 = !debug.0;
+    }
+}
+
+fn draw_hitboxes(
+    debug: Res<DebugOverlay>,
+    images: Res<Assets<Image>>,
+    colliders: ColliderQuery,
+    mut gizmos: Gizmos,
+) {
+    if !debug.0 {
+        return;
+    }
+
+    for (_, transform, collider, texture, sprite) in colliders.iter() {
+        let hitbox = hitbox(transform, collider, texture, sprite, &images);
+        let color = match collider.layer {
+            CollisionLayer::Player => Color::GREEN,
+            CollisionLayer::Pickup => Color::YELLOW,
+            CollisionLayer::Hazard => Color::RED,
+            CollisionLayer::Thief => Color::FUCHSIA,
+            CollisionLayer::Collector | CollisionLayer::Shield => Color::CYAN,
+            CollisionLayer::Ghost => Color::GRAY,
+        };
+
+        match hitbox.shape {
+            ColliderShape::Aabb => {
+                gizmos.rect_2d(hitbox.center, 0.0, hitbox.half * 2.0, color);
+            }
+            ColliderShape::Circle => {
+                gizmos.circle_2d(hitbox.center, hitbox.radius(), color);
+            }
+        }
+    }
+}
+
+/* =======================
+   HEATMAP TELEMETRY
+======================= */
+
+#[derive(Clone, Copy)]
+enum HeatmapChannel {
+    BitHit,
+    MissedPresent,
+    FanSpawn,
+}
+
+impl HeatmapChannel {
+    const ALL: [HeatmapChannel; 3] = [
+        HeatmapChannel::BitHit,
+        HeatmapChannel::MissedPresent,
+        HeatmapChannel::FanSpawn,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            HeatmapChannel::BitHit => "BIT HITS",
+            HeatmapChannel::MissedPresent => "MISSED",
+            HeatmapChannel::FanSpawn => "FANS",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            HeatmapChannel::BitHit => Color::RED,
+            HeatmapChannel::MissedPresent => Color::GOLD,
+            HeatmapChannel::FanSpawn => Color::CYAN,
+        }
+    }
+}
+
+// 🗺 Where along x things happen, summed over every run and saved on each crash
+#[derive(Resource, Serialize, Deserialize)]
+#[serde(default)]
+struct Heatmap {
+    runs: u32,
+    // Santa's x when a bit got through
+    bit_hits: Vec<u32>,
+    // The present's x as it left the screen
+    missed_presents: Vec<u32>,
+    fan_spawns: Vec<u32>,
+}
+
+impl Default for Heatmap {
+    fn default() -> Self {
+        Self {
+            runs: 0,
+            bit_hits: vec![0; Self::BINS],
+            missed_presents: vec![0; Self::BINS],
+            fan_spawns: vec![0; Self::BINS],
+        }
+    }
+}
+
+impl Heatmap {
+    const KEY: &'static str = "heatmap";
+    // Covers the ±320 player clamp and the drift limit with a bin to spare
+    const MIN_X: f32 = -340.0;
+    const BIN_WIDTH: f32 = 20.0;
+    const BINS: usize = 34;
+    // Bins this far out count as the edge zone in the F3 summary
+    const EDGE_X: f32 = 280.0;
+
+    fn load() -> Self {
+        let mut heatmap: Self = storage::load(Self::KEY)
+            .and_then(|text| ron::from_str(&text).ok())
+            .unwrap_or_default();
+
+        // A save from a different bin layout is padded or cut to fit
+        for channel in HeatmapChannel::ALL {
+            heatmap.bins_mut(channel).resize(Self::BINS, 0);
+        }
+        heatmap
+    }
+
+    fn save(&self) {
+        save_ron(Self::KEY, self);
+    }
+
+    fn bins(&self, channel: HeatmapChannel) -> &[u32] {
+        match channel {
+            HeatmapChannel::BitHit => &self.bit_hits,
+            HeatmapChannel::MissedPresent => &self.missed_presents,
+            HeatmapChannel::FanSpawn => &self.fan_spawns,
+        }
+    }
+
+    fn bins_mut(&mut self, channel: HeatmapChannel) -> &mut Vec<u32> {
+        match channel {
+            HeatmapChannel::BitHit => &mut self.bit_hits,
+            HeatmapChannel::MissedPresent => &mut self.missed_presents,
+            HeatmapChannel::FanSpawn => &mut self.fan_spawns,
+        }
+    }
+
+    fn bin_x(i: usize) -> f32 {
+        Self::MIN_X + Self::BIN_WIDTH * i as f32
+    }
+
+    fn record(&mut self, channel: HeatmapChannel, x: f32) {
+        let bin = ((x - Self::MIN_X) / Self::BIN_WIDTH).max(0.0) as usize;
+        self.bins_mut(channel)[bin.min(Self::BINS - 1)] += 1;
+    }
+
+    // Share of a channel in bins wholly outside ±EDGE_X
+    fn edge_share(&self, channel: HeatmapChannel) -> f32 {
+        let bins = self.bins(channel);
+        let total: u32 = bins.iter().sum();
+        let edge: u32 = bins
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| {
+                let x = Self::bin_x(*i);
+                x + Self::BIN_WIDTH <= -Self::EDGE_X || x >= Self::EDGE_X
+            })
+            .map(|(_, count)| count)
+            .sum();
+        edge as f32 / total.max(1) as f32
+    }
+}
+
+// Saved on every crash so the bins survive quitting at the crash screen,
+// but a continued run only counts once
+fn save_heatmap(
+    headless: Res<Headless>,
+    mut heatmap: ResMut<Heatmap>,
+    mut events: EventReader<GameEvent>,
+    mut run: RunEnd,
+) {
+    let crashed = events.read().any(|e| matches!(e, GameEvent::Crashed(_)));
+    let ended = run.just_ended();
+    if !crashed && !ended {
+        return;
+    }
+
+    if ended {
+        heatmap.runs += 1;
+    }
+    if !headless.0 {
+        heatmap.save();
+    }
+}
+
+// F3: one bar per channel and bin along the bottom, each channel scaled to its own peak
+fn draw_heatmap(debug: Res<DebugOverlay>, heatmap: Res<Heatmap>, mut gizmos: Gizmos) {
+    if !debug.0 {
+        return;
+    }
+
+    const BASE_Y: f32 = -355.0;
+    const HEIGHT: f32 = 120.0;
+
+    for (c, channel) in HeatmapChannel::ALL.into_iter().enumerate() {
+        let bins = heatmap.bins(channel);
+        let peak = bins.iter().copied().max().unwrap_or(0).max(1);
+
+        for (i, count) in bins.iter().enumerate() {
+            if *count == 0 {
+                continue;
+            }
+            let x = Heatmap::bin_x(i) + Heatmap::BIN_WIDTH * (c as f32 + 1.0) / 4.0;
+            let top = BASE_Y + HEIGHT * *count as f32 / peak as f32;
+            gizmos.line_2d(Vec2::new(x, BASE_Y), Vec2::new(x, top), channel.color());
+        }
+    }
+
+    // 📏 Spawn range (±300) and player clamp (±320)
+    for (x, color) in [(300.0, Color::GRAY), (320.0, Color::WHITE)] {
+        for side in [-1.0, 1.0] {
+            let x = x * side;
+            gizmos.line_2d(Vec2::new(x, BASE_Y), Vec2::new(x, BASE_Y + HEIGHT), color);
+        }
+    }
+}
+
+/* =======================
+   ACTIONS
+======================= */
+
+/// What Santa is asked to do this frame. Gameplay only reads this; the keyboard,
+/// a [`bot`] or a simulation caller fills it in.
+#[derive(Resource, Clone, Copy, Default, Debug, PartialEq)]
+pub struct Actions {
+    /// -1..1 per axis; y only counts in vertical mode.
+    pub movement: Vec2,
+    /// Held to overclock.
+    pub overclock: bool,
+    /// Starts a dash this frame (if off cooldown).
+    pub dash: bool,
+}
+
+// Who fills in `Actions` each frame
+#[derive(Resource, Clone, Copy)]
+enum InputSource {
+    Keyboard,
+    Bot(BotProfile),
+    // Set directly by a simulation caller before each step
+    External,
+}
+
+fn keyboard_actions(
+    source: Res<InputSource>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut actions: ResMut<Actions>,
+    mut last_tap: Local<Option<(KeyCode, f32)>>,
+) {
+    if !matches!(*source, InputSource::Keyboard) {
+        return;
+    }
+
+    let axis = |neg, pos| f32::from(keyboard.pressed(pos)) - f32::from(keyboard.pressed(neg));
+    actions.movement = Vec2::new(
+        axis(KeyCode::ArrowLeft, KeyCode::ArrowRight),
+        axis(KeyCode::ArrowDown, KeyCode::ArrowUp),
+    );
+    actions.overclock = keyboard.pressed(KeyCode::Space);
+
+    // ⚡ SHIFT, or double-tap an arrow. Stays set until `dash_system` takes it,
+    // so a press in a frame without a fixed tick isn't lost
+    let now = time.elapsed_seconds();
+    if keyboard.just_pressed(KeyCode::ShiftLeft) || keyboard.just_pressed(KeyCode::ShiftRight) {
+        actions.dash = true;
+    }
+
+    for key in [KeyCode::ArrowLeft, KeyCode::ArrowRight] {
+        if keyboard.just_pressed(key) {
+            if matches!(*last_tap, Some((last, at)) if last == key && now - at < 0.25) {
+                actions.dash = true;
+                *last_tap = None;
+            } else {
+                *last_tap = Some((key, now));
+            }
+        }
+    }
+}
+
+/* =======================
+   PLAYER MOVEMENT
+======================= */
+
+fn dash_system(
+    mut actions: ResMut<Actions>,
+    time: Res<Time>,
+    state: Res<GameState>,
+    mut dash: ResMut<Dash>,
+    mut data: ResMut<GameData>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Transform, &mut Velocity), With<Player>>,
+) {
+    // A dash is a single press: used up whether it fires or not
+    let pressed = std::mem::take(&mut actions.dash);
+    if *state != GameState::Playing {
+        return;
+    }
+
+    dash.active.tick(time.delta());
+    dash.cooldown.tick(time.delta());
+
+    if actions.movement.x != 0.0 {
+        dash.facing = actions.movement.x.signum();
+    }
+
+    // ⚡ Dash where Santa faces
+    if pressed && dash.cooldown.finished() && !dash.is_dashing() {
+        dash.direction = dash.facing;
+        dash.active.reset();
+        dash.cooldown.reset();
+
+        // 🔥 Dashing isn't free
+        data.heat = (data.heat + 12.0).clamp(0.0, 100.0);
+    }
+
+    if !dash.is_dashing() {
+        return;
+    }
+
+    let Ok((mut transform, mut velocity)) = query.get_single_mut() else {
+        return;
+    };
+
+    // 🏃 Come out of the dash at full speed
+    velocity.0.x = dash.direction * config.movement.max_speed;
+
+    // 💨 140 px over the dash window
+    let speed = 140.0 / dash.active.duration().as_secs_f32();
+    transform.translation.x += dash.direction * speed * time.delta_seconds();
+    transform.translation.x = transform.translation.x.clamp(-320.0, 320.0);
+}
+
+fn player_movement(
+    actions: Res<Actions>,
+    time: Res<Time>,
+    state: Res<GameState>,
+    dash: Res<Dash>,
+    data: Res<GameData>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Transform, &mut Velocity), With<Player>>,
+) {
+    if *state != GameState::Playing || dash.is_dashing() {
+        return;
+    }
+
+    let Ok((mut transform, mut velocity)) = query.get_single_mut() else {
+        return;
+    };
+    let tuning = &config.movement;
+    let dt = time.delta_seconds();
+    let mut input = actions.movement.clamp(Vec2::NEG_ONE, Vec2::ONE);
+
+    // ↕ Optional vertical band
+    if !config.vertical_movement {
+        input.y = 0.0;
+    }
+
+    // 🧊 Overclocking makes Santa slide more the hotter it runs
+    let momentum = if data.overloading {
+        tuning.overclock_momentum * data.heat / 100.0
+    } else {
+        0.0
+    };
+
+    velocity.0.x = accelerate(velocity.0.x, input.x, momentum, tuning, dt);
+    velocity.0.y = accelerate(velocity.0.y, input.y, momentum, tuning, dt);
+
+    // ✅ APPLY MOVEMENT ONCE
+    transform.translation += (velocity.0 * dt).extend(0.0);
+
+    // 🔒 SCREEN BOUNDS
+    let min = Vec2::new(-320.0, MovementConfig::FLOOR_Y);
+    let max = Vec2::new(
+        320.0,
+        if config.vertical_movement {
+            tuning.band_top
+        } else {
+            MovementConfig::FLOOR_Y
+        },
+    );
+    let pos = transform.translation.truncate();
+    let clamped = pos.clamp(min, max);
+
+    if clamped.x != pos.x {
+        velocity.0.x = 0.0;
+    }
+    if clamped.y != pos.y {
+        velocity.0.y = 0.0;
+    }
+    transform.translation = clamped.extend(transform.translation.z);
+
+    // ↗ Lean into the motion
+    let lean = velocity.0.x / tuning.max_speed.max(1.0) * tuning.max_lean_degrees;
+    transform.rotation = Quat::from_rotation_z(-lean.to_radians());
+}
+
+fn accelerate(velocity: f32, input: f32, momentum: f32, tuning: &MovementConfig, dt: f32) -> f32 {
+    let velocity = if input != 0.0 {
+        // 🔁 Turning around brakes and accelerates at once
+        let mut accel = tuning.acceleration;
+        if input * velocity < 0.0 {
+            accel += tuning.deceleration;
+        }
+        velocity + input * accel * dt
+    } else {
+        let brake = tuning.deceleration * (1.0 - momentum).clamp(0.05, 1.0) * dt;
+
+        if velocity.abs() <= brake {
+            0.0
+        } else {
+            velocity - brake * velocity.signum()
+        }
+    };
+
+    velocity.clamp(-tuning.max_speed, tuning.max_speed)
+}
+
+fn overload_system(
+    tick: Tick,
+    actions: Res<Actions>,
+    mut run: RunTally,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+    boss: Res<Boss>,
+) {
+    if !tick.playing() {
+        return;
+    }
+
+    // 🔊 On the frame overclocking starts
+    if actions.overclock && !run.data.overloading {
+        commands.spawn(AudioBundle {
+            source: asset_server.load("audio/Fire.wav"),
+            settings: PlaybackSettings::DESPAWN,
+        });
+    }
+
+    if actions.overclock {
+        run.data.overloading = true;
+        run.data.speed_multiplier = 2.0;
+        run.stats.overclock_seconds += tick.time.delta_seconds();
+
+        // 🧊 Free overclocking right after beating the Grinch
+        if !boss.is_cooling() {
+            run.data.heat += 0.8;
+        }
+    } else {
+        run.data.overloading = false;
+        run.data.speed_multiplier = 1.0;
+
+        // ❄ Blizzard air helps passive cooling
+        run.data.heat -= 0.4 + 0.3 * tick.weather.intensity(WeatherKind::Blizzard);
+    }
+
+    // 🔥 AC failure warms everything up
+    run.data.heat += 0.25 * tick.weather.intensity(WeatherKind::HeatWave);
+
+    run.data.heat = run.data.heat.clamp(0.0, 100.0);
+}
+fn spawn_presents(
+    mut commands: Commands,
+    state: Res<GameState>,
+    time: Res<Time>,
+    mut timer: ResMut<SpawnTimer>,
+    difficulty: Res<Difficulty>, // ✅ REQUIRED
+    mut rng: ResMut<GameRng>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+
+    timer.0.set_duration(std::time::Duration::from_secs_f32(
+        (1.2_f32 / difficulty.level).clamp(0.3_f32, 1.2_f32),
+    ));
+
+    if timer.0.just_finished() {
+        let x = rng.gen_range(-300.0_f32..300.0_f32);
+
+        // ✨ One in twelve is golden
+        let golden = rng.gen_ratio(1, 12);
+        let color = if golden { Color::GOLD } else { Color::WHITE };
+
+        commands.add(
+            SpawnPooled::new(
+                PoolKind::Present,
+                Transform {
+                    translation: Vec3::new(x, 300.0, 0.0),
+                    scale: Vec3::splat(0.5),
+                    ..default()
+                },
+            )
+            .with((Present { golden }, Sprite { color, ..default() })),
+        );
+    }
+}
+
+fn move_presents(
+    mut commands: Commands,
+    tick: Tick,
+    mut run: RunTally,
+    mut heatmap: ResMut<Heatmap>,
+    mut query: Query<(Entity, &mut Transform, &Pooled), With<Present>>,
+) {
+    if !tick.playing() {
+        return;
+    }
+
+    let base_speed = 3.0;
+    let speed = base_speed * tick.difficulty.level * run.data.speed_multiplier;
+
+    for (entity, mut transform, pooled) in query.iter_mut() {
+        if !pooled.active {
+            continue;
+        }
+
+        transform.translation.y -= speed * tick.time_scale.value;
+        drift(
+            &mut transform,
+            &tick.weather,
+            tick.time.delta_seconds() * tick.time_scale.value,
+        );
+
+        if transform.translation.y < -350.0 {
+            // 💔 Missed present breaks the run.combo
+            run.combo.break_combo();
+            run.stats.presents_missed += 1;
+            heatmap.record(HeatmapChannel::MissedPresent, transform.translation.x);
+            commands.add(ReleasePooled(entity));
+        }
+    }
+}
+
+type Catchers<'w, 's> =
+    Query<'w, 's, (Has<Player>, &'static Transform), Or<(With<Player>, With<Companion>)>>;
+
+fn collect_presents(
+    mut fx: Effects,
+    mut run: RunTally,
+    mut overlaps: RoundOverlaps,
+    config: Res<GameConfig>,
+    // 🦌 The reindeer catches on Santa's behalf
+    catchers: Catchers,
+    mut presents: Query<(&Transform, &mut Collider, &Present)>,
+    mut boss: ResMut<Boss>,
+) {
+    let Some(overlaps) = overlaps.read() else {
+        return;
+    };
+
+    for overlap in overlaps {
+        let Ok((by_santa, catcher)) = catchers.get(overlap.a) else {
+            continue;
+        };
+
+        let Ok((transform, mut collider, present)) = presents.get_mut(overlap.b) else {
+            continue;
+        };
+
+        // ✅ prevents double collection in one frame
+        if collider.layer == CollisionLayer::Ghost {
+            continue;
+        }
+        collider.layer = CollisionLayer::Ghost;
+
+        // 🎯 SCORE LOGIC
+        let base = if run.data.overloading {
+            25 // BONUS
+        } else {
+            10
+        };
+        let base = if present.golden { base * 3 } else { base };
+
+        run.combo.add_catch(run.data.overloading);
+        let multiplier = run.combo.multiplier();
+
+        // ⛰ Santa catching higher up (less reaction time) pays more
+        let altitude = if by_santa && config.vertical_movement {
+            config.movement.altitude(catcher.translation.y)
+        } else {
+            0.0
+        };
+        let altitude_bonus = 1.0 + altitude * config.movement.altitude_bonus;
+        let points = (base * multiplier) as f32 * altitude_bonus;
+        let points = points.round() as u32;
+        run.data.score += points;
+
+        run.stats.presents_collected += 1;
+        run.stats.golden_collected += u32::from(present.golden);
+        run.stats.max_combo = run.stats.max_combo.max(run.combo.count);
+        fx.events.send(GameEvent::PresentCaught {
+            golden: present.golden,
+            heat: run.data.heat,
+        });
+
+        // 👾 Overclocked catches chip away at the Grinch virus
+        if run.data.overloading && boss.damage() {
+            run.data.score += Boss::BONUS;
+            boss.cooldown.reset();
+            run.stats.bosses_defeated += 1;
+            fx.events.send(GameEvent::BossDefeated);
+        }
+
+        // 💬 "+50 x3" popup where the present was caught
+        fx.commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    format!("+{} x{}", points, multiplier),
+                    TextStyle {
+                        font: fx.asset_server.load("fonts/PixelOperator8-Bold.ttf"),
+                        font_size: 16.0,
+                        color: Color::GOLD,
+                    },
+                ),
+                transform: Transform::from_translation(
+                    transform.translation.truncate().extend(5.0),
+                ),
+                ..default()
+            },
+            ScorePopup {
+                timer: Timer::from_seconds(0.8, TimerMode::Once),
+            },
+        ));
+
+        // 🔊 PRESENT COLLECT SOUND (clean & satisfying)
+        fx.commands.spawn(AudioBundle {
+            source: fx.asset_server.load("audio/PRESENT.wav"),
+            settings: PlaybackSettings {
+                volume: Volume::new(0.55), // ✅ balanced volume
+                ..default()
+            },
+        });
+
+        // 🧹 REMOVE PRESENT
+        fx.commands.add(ReleasePooled(overlap.b));
+    }
+}
+
+fn combo_decay(
+    time: Res<Time>,
+    data: Res<GameData>,
+    state: Res<GameState>,
+    mut combo: ResMut<Combo>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    // 🌡 Touching the near-crash zone breaks the combo
+    let hot = data.heat >= 85.0;
+    if hot && !combo.was_hot {
+        combo.break_combo();
+    }
+    combo.was_hot = hot;
+
+    // ⏳ No catch for a while → lose one step per tick
+    combo.decay.tick(time.delta());
+    if combo.decay.just_finished() {
+        combo.count = combo.count.saturating_sub(1);
+    }
+}
+
+fn animate_score_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut popups: Query<(Entity, &mut Transform, &mut Text, &mut ScorePopup)>,
+) {
+    for (entity, mut transform, mut text, mut popup) in popups.iter_mut() {
+        popup.timer.tick(time.delta());
+
+        // ⬆ Float up and fade out
+        transform.translation.y += 40.0 * time.delta_seconds();
+        text.sections[0]
+            .style
+            .color
+            .set_a(1.0 - popup.timer.fraction());
+
+        if popup.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn record_high_score(
+    state: Res<GameState>,
+    data: Res<GameData>,
+    stats: Res<RunStats>,
+    mut scores: ResMut<HighScores>,
+    mut recorded: ResMut<HighScoreRecorded>,
+) {
+    if *state != GameState::Crashed || recorded.0 {
+        return;
+    }
+
+    scores.record(HighScore {
+        score: data.score,
+        max_combo: stats.max_combo,
+        continues: stats.continues_used,
+    });
+
+    recorded.0 = true; // 🔒 once per run
+}
+
+fn invulnerability_flash(
+    time: Res<Time>,
+    mut lives: ResMut<Lives>,
+    state: Res<GameState>,
+    mut player: Query<&mut Sprite, With<Player>>,
+) {
+    if *state != GameState::Playing {
+        return;
+    }
+
+    let Ok(mut sprite) = player.get_single_mut() else {
+        return;
+    };
+
+    lives.invulnerable.tick(time.delta());
+
+    // ✨ Blink ~10 times a second while invulnerable
+    let visible =
+        !lives.is_invulnerable() || (lives.invulnerable.elapsed_secs() * 5.0).fract() < 0.5;
+    sprite.color.set_a(if visible { 1.0 } else { 0.2 });
+}
+
+fn checkpoint_system(
+    time: Res<Time>,
+    state: Res<GameState>,
+    config: Res<GameConfig>,
+    data: Res<GameData>,
+    difficulty: Res<Difficulty>,
+    mut checkpoint: ResMut<Checkpoint>,
+    mut notices: EventWriter<Notice>,
+) {
+    if *state != GameState::Playing || !config.lives {
+        return;
+    }
+
+    checkpoint.timer.tick(time.delta());
+
+    // 💾 Snapshot score & difficulty
+    if checkpoint.timer.just_finished() {
+        checkpoint.score = data.score;
+        checkpoint.level = difficulty.level;
+        checkpoint.time_alive = difficulty.time_alive;
+        notices.send(Notice::new(NoticeLevel::Info, "CHECKPOINT SAVED"));
+    }
+}
+
+fn delivery_quota_system(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut quota: ResMut<DeliveryQuota>,
+    mut state: ResMut<GameState>,
+    mut stats: ResMut<RunStats>,
+    mut notices: EventWriter<Notice>,
+    mut events: EventWriter<GameEvent>,
+) {
+    if *state != GameState::Playing || !config.delivery_quota {
+        return;
+    }
+
+    quota.timer.tick(time.delta());
+
+    if !quota.timer.just_finished() {
+        return;
+    }
+
+    // 📦 End of phase → check deliveries
+    let delivered = stats.presents_collected - quota.delivered_at_start;
+
+    if delivered < quota.required() {
+        *state = GameState::Crashed;
+        stats.crash_cause = Some(CrashCause::DeliveryFailed);
+        events.send(GameEvent::Crashed(CrashCause::DeliveryFailed));
+        return;
+    }
+
+    notices.send(Notice::new(
+        NoticeLevel::Ok,
+        format!("PHASE {} DELIVERED", quota.phase),
+    ));
+
+    quota.phase += 1;
+    quota.delivered_at_start = stats.presents_collected;
+    quota.missed_at_start = stats.presents_missed;
+}
+
+fn crash_check(
+    mut state: ResMut<GameState>,
+    data: Res<GameData>,
+    mut stats: ResMut<RunStats>,
+    mut events: EventWriter<GameEvent>,
+) {
+    if *state == GameState::Playing && data.heat >= 100.0 {
+        *state = GameState::Crashed;
+        stats.crash_cause = Some(CrashCause::Overheat);
+        events.send(GameEvent::Crashed(CrashCause::Overheat));
+    }
+}
+fn fade_game_over(
+    mut fade: ResMut<GameOverFade>,
+    state: Res<GameState>,
+    mut query: Query<&mut BackgroundColor, With<GameOverOverlay>>,
+    time: Res<Time>,
+) {
+    if *state != GameState::Crashed {
+        fade.alpha = 0.0;
+        return;
+    }
+
+    fade.alpha = (fade.alpha + time.delta_seconds() * 0.6).clamp(0.0, 0.85);
+
+    if let Ok(mut bg) = query.get_single_mut() {
+        bg.0.set_a(fade.alpha);
+    }
+}
+
+fn spawn_game_over_overlay(
+    mut fx: Effects,
+    state: Res<GameState>,
+    run: RunTally,
+    config: Res<GameConfig>,
+    missions: Res<Missions>,
+    profile: Profile,
+    query: Query<Entity, With<GameOverOverlay>>,
+) {
+    if *state != GameState::Crashed || !query.is_empty() {
+        return;
+    }
+
+    let font = fx.asset_server.load("fonts/PixelOperator8-Bold.ttf");
+    let cause = run.stats.crash_cause.unwrap_or(CrashCause::Overheat);
+
+    fx.commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(18.0), // 🔥 spacing
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.0).into(),
+                ..default()
+            },
+            GameOverOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                cause.title(),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 42.0,
+                    color: Color::RED,
+                },
+            ));
+
+            parent.spawn(TextBundle::from_section(
+                cause.detail(),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                },
+            ));
+
+            parent.spawn(TextBundle::from_section(
+                "CHRISTMAS RUINED",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 28.0,
+                    color: Color::ORANGE_RED,
+                },
+            ));
+
+            parent.spawn(TextBundle::from_section(
+                format!(
+                    "SCORE: {}  MAX COMBO: {}  MISSED: {}  STOLEN: {}",
+                    run.data.score,
+                    run.stats.max_combo,
+                    run.stats.presents_missed,
+                    run.stats.presents_stolen
+                ),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    color: Color::GOLD,
+                },
+            ));
+
+            // 🎯 MISSIONS
+            for mission in &missions.active {
+                let color = if mission.done {
+                    Color::GREEN
+                } else {
+                    Color::GRAY
+                };
+                parent.spawn(TextBundle::from_section(
+                    mission.status(),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color,
+                    },
+                ));
+            }
+
+            parent.spawn(TextBundle::from_section(
+                format!(
+                    "COOKIES: {}  ACHIEVEMENTS: {}/{}",
+                    profile.wallet.cookies,
+                    profile.achievements.unlocked.len(),
+                    ACHIEVEMENTS.len()
+                ),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    color: Color::BEIGE,
+                },
+            ));
+
+            // 🏆 HIGH SCORES
+            for (rank, entry) in profile.scores.0.iter().enumerate() {
+                // ♻ Continued runs are flagged
+                let continued = if entry.continues > 0 {
+                    format!("  +{} REBOOT", entry.continues)
+                } else {
+                    String::new()
+                };
+
+                parent.spawn(TextBundle::from_section(
+                    format!(
+                        "{}. {:>6}  COMBO {}{}",
+                        rank + 1,
+                        entry.score,
+                        entry.max_combo,
+                        continued
+                    ),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                    },
+                ));
+            }
+
+            if config.lives && run.stats.reboots_left() > 0 {
+                parent.spawn(TextBundle::from_section(
+                    format!("PRESS C TO WARM REBOOT ({} LEFT)", run.stats.reboots_left()),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        color: Color::CYAN,
+                    },
+                ));
+            }
+
+            parent.spawn(TextBundle::from_section(
+                "PRESS R TO REBOOT",
+                TextStyle {
+                    font,
+                    font_size: 20.0,
+                    color: Color::GRAY,
+                },
+            ));
+        });
+}
+
+/* =======================
+   UI SETUP
+======================= */
+
+fn setup_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font = asset_server.load("fonts/PixelOperator8-Bold.ttf");
+
+    // SCORE
+    commands.spawn((
+        TextBundle::from_section(
+            "SCORE: 0",
+            TextStyle {
+                font: font.clone(),
+                font_size: 28.0,
+                color: Color::GREEN,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        ScoreText,
+    ));
+
+    // HEAT
+    commands.spawn((
+        TextBundle::from_section(
+            "HEAT: 0%",
+            TextStyle {
+                font: font.clone(),
+                font_size: 28.0,
+                color: Color::RED,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(45.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        HeatText,
+    ));
+
+    // COMBO
+    commands.spawn((
+        TextBundle::from_section(
+            "COMBO: 0  x1",
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.0,
+                color: Color::GOLD,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(80.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        ComboText,
+    ));
+
+    // DELIVERY QUOTA (hidden unless the mode is on)
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.0,
+                color: Color::CYAN,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(110.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        QuotaText,
+    ));
+
+    // MISSIONS
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 12.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(140.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        MissionText,
+    ));
+
+    // LIVES (hidden unless the mode is on)
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.0,
+                color: Color::PINK,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        }),
+        LivesText,
+    ));
+
+    // DASH COOLDOWN
+    commands.spawn((
+        TextBundle::from_section(
+            "DASH: READY",
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.0,
+                color: Color::CYAN,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(35.0),
+            right: Val::Px(10.0),
+            ..default()
+        }),
+        DashText,
+    ));
+
+    // COMPANION (hidden until one is along)
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.0,
+                color: Color::CYAN,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            right: Val::Px(10.0),
+            ..default()
+        }),
+        CompanionText,
+    ));
+
+    // WEATHER (forecast + active event)
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Percent(0.0),
+            right: Val::Percent(0.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        WeatherText,
+    ));
+
+    // BOSS HEALTH (only during an encounter)
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(70.0),
+                    left: Val::Percent(30.0),
+                    width: Val::Percent(40.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                ..default()
+            },
+            BossBar,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "GRINCH.EXE",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        color: Color::LIME_GREEN,
+                    },
+                ),
+                BossLabel,
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(12.0),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.2, 0.2, 0.2, 0.8).into(),
+                    ..default()
+                })
+                .with_children(|bar| {
+                    bar.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: Color::LIME_GREEN.into(),
+                            ..default()
+                        },
+                        BossBarFill,
+                    ));
+                });
+        });
+
+    // DEBUG (F3)
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 12.0,
+                color: Color::GRAY,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        }),
+        DebugText,
+    ));
+
+    // SYSTEM LOG (newest line at the bottom)
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexStart,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            ..default()
+        },
+        LogPanel,
+    ));
+}
+
+/* =======================
+   UPDATE UI
+======================= */
+
+type HudTexts<'w, 's> = ParamSet<
+    'w,
+    's,
+    (
+        Query<'static, 'static, &'static mut Text, With<ScoreText>>,
+        Query<'static, 'static, &'static mut Text, With<HeatText>>,
+        Query<'static, 'static, &'static mut Text, With<ComboText>>,
+        Query<'static, 'static, &'static mut Text, With<QuotaText>>,
+        Query<'static, 'static, &'static mut Text, With<LivesText>>,
+        Query<'static, 'static, &'static mut Text, With<DashText>>,
+    ),
+>;
+
+fn update_ui(
+    run: RunTally,
+    config: Res<GameConfig>,
+    quota: Res<DeliveryQuota>,
+    lives: Res<Lives>,
+    dash: Res<Dash>,
+    player: Query<&Transform, With<Player>>,
+    mut texts: HudTexts,
+) {
+    if let Ok(mut score_text) = texts.p0().get_single_mut() {
+        score_text.sections[0].value = format!("SCORE: {}", run.data.score);
+    }
+
+    if let Ok(mut heat_text) = texts.p1().get_single_mut() {
+        heat_text.sections[0].value = format!("HEAT: {}%", run.data.heat as i32);
+
+        heat_text.sections[0].style.color = if run.data.heat > 70.0 {
+            Color::ORANGE_RED
+        } else {
+            Color::RED
+        };
+    }
+
+    if let Ok(mut combo_text) = texts.p2().get_single_mut() {
+        combo_text.sections[0].value =
+            format!("COMBO: {}  x{}", run.combo.count, run.combo.multiplier());
+
+        if config.vertical_movement {
+            if let Ok(player) = player.get_single() {
+                let altitude = config.movement.altitude(player.translation.y);
+                let bonus = altitude * config.movement.altitude_bonus * 100.0;
+                combo_text.sections[0].value += &format!("  ALT +{:.0}%", bonus);
+            }
+        }
+    }
+
+    if let Ok(mut quota_text) = texts.p3().get_single_mut() {
+        quota_text.sections[0].value = if config.delivery_quota {
+            format!(
+                "QUOTA {}: {}/{}  MISSED: {}  {:.0}s",
+                quota.phase,
+                run.stats.presents_collected - quota.delivered_at_start,
+                quota.required(),
+                run.stats.presents_missed - quota.missed_at_start,
+                quota.timer.remaining_secs()
+            )
+        } else {
+            String::new()
+        };
+    }
+
+    if let Ok(mut lives_text) = texts.p4().get_single_mut() {
+        lives_text.sections[0].value = if config.lives {
+            format!("LIVES: {}", lives.remaining)
+        } else {
+            String::new()
+        };
+    }
+
+    if let Ok(mut dash_text) = texts.p5().get_single_mut() {
+        if dash.cooldown.finished() {
+            dash_text.sections[0].value = "DASH: READY".to_string();
+            dash_text.sections[0].style.color = Color::CYAN;
+        } else {
+            dash_text.sections[0].value = format!("DASH: {:.1}s", dash.cooldown.remaining_secs());
+            dash_text.sections[0].style.color = Color::GRAY;
+        }
+    }
+}
+
+/* =======================
+   MUSIC
+======================= */
+
+fn play_music(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        AudioBundle {
+            source: asset_server.load("audio/BACKGROUNG_LOOP.wav"),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Loop,
+                volume: Volume::new(0.25), // ✅ soft & clean
+                ..default()
+            },
+        },
+        BackgroundMusic,
+    ));
+}
+
+/* =======================
+   GAME RESTART
+======================= */
+
+// 🔄 Everything a reboot (R or C) rewinds
+#[derive(SystemParam)]
+struct Round<'w> {
+    state: ResMut<'w, GameState>,
+    data: ResMut<'w, GameData>,
+    difficulty: ResMut<'w, Difficulty>,
+    combo: ResMut<'w, Combo>,
+    stats: ResMut<'w, RunStats>,
+    quota: ResMut<'w, DeliveryQuota>,
+    lives: ResMut<'w, Lives>,
+    fade: ResMut<'w, GameOverFade>,
+    played: ResMut<'w, CrashSoundPlayed>,
+    recorded: ResMut<'w, HighScoreRecorded>,
+    freeze: ResMut<'w, HitFreeze>,
+    headless: Res<'w, Headless>,
+}
+
+impl Round<'_> {
+    // ❄ A bit hit still freezing when something else crashed the run
+    // mustn't finish (and crash) the next one
+    fn thaw(&mut self) {
+        self.freeze.active = false;
+        self.freeze.timer.reset();
+    }
+}
+
+type PlayerReset<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Visibility,
+        &'static mut Velocity,
+        &'static mut Transform,
+    ),
+    With<Player>,
+>;
+
+type RebootOverlay<'w, 's> = Query<'w, 's, Entity, Or<(With<GameOverOverlay>, With<ScorePopup>)>>;
+
+fn restart_game(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut round: Round,
+    mut commands: Commands,
+    game_over_ui: RebootOverlay,
+    mut player: PlayerReset,
+    mut checkpoint: ResMut<Checkpoint>,
+    mut dash: ResMut<Dash>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyR) && *round.state == GameState::Crashed {
+        // 🔄 Reset gameplay data
+        round.data.score = 0;
+        round.data.heat = 0.0;
+        round.data.speed_multiplier = 1.0;
+        round.data.overloading = false;
+
+        // 🔄 Reset difficulty
+        round.difficulty.level = 1.0;
+        round.difficulty.time_alive = 0.0;
+
+        // 🔄 Reset combo & run stats
+        *round.combo = Combo::default();
+        *round.stats = RunStats::default();
+        *round.quota = DeliveryQuota::default();
+        *round.lives = Lives::default();
+        *checkpoint = Checkpoint::default();
+        *dash = Dash::default();
+        round.recorded.0 = false;
+        round.thaw();
+
+        // 🎲 Reseed + clear weather, boss and elves
+        commands.add(reset_round);
+
+        // 🔄 Reset fade
+        round.fade.alpha = 0.0;
+
+        // 🔄 Switch state
+        *round.state = GameState::Playing;
+
+        // 🧹 Remove GAME OVER overlay + leftover popups
+        for e in game_over_ui.iter() {
+            commands.entity(e).despawn_recursive();
+        }
+
+        // 👀 SHOW SANTA AGAIN (standing still)
+        if let Ok((mut vis, mut velocity, mut transform)) = player.get_single_mut() {
+            *vis = Visibility::Visible;
+            velocity.0 = Vec2::ZERO;
+            transform.translation.x = 0.0;
+            transform.translation.y = MovementConfig::FLOOR_Y;
+            transform.rotation = Quat::IDENTITY;
+        }
+        round.played.0 = false;
+
+        if !round.headless.0 {
+            println!("🔁 SYSTEM REBOOTED");
+        }
+    }
+}
+
+// Round state that restart_game can't take as params (Bevy caps systems at 16)
+fn reset_round(world: &mut World) {
+    let mut rng = world.resource_mut::<GameRng>();
+    rng.restart();
+    let seed = rng.seed;
+    if !world.resource::<Headless>().0 {
+        println!("🎲 SEED {seed}");
+    }
+
+    let weather = Weather::new(seed);
+
+    world.insert_resource(weather);
+    world.insert_resource(Boss::default());
+    world.insert_resource(Missions::default());
+    world.insert_resource(RunTelemetry::default());
+    world.resource_mut::<LifetimeStats>().folded = Totals::default();
+    insert_round_clocks(world);
+
+    // 🦌 Unlocks stay, the shield recharges
+    let mut companions = world.resource_mut::<Companions>();
+    let duration = companions.shield.duration();
+    companions.shield.tick(duration);
+
+    let leftovers: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<GrinchElf>, With<Coal>, With<Companion>)>>()
+        .iter(world)
+        .collect();
+    for entity in leftovers {
+        world.despawn(entity);
+    }
+
+    // 🧹 Whatever was still falling when the last run crashed, snow included
+    let falling: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(
+            With<Present>,
+            With<Fan>,
+            With<CorruptedBit>,
+            With<Snowflake>,
+        )>>()
+        .iter(world)
+        .collect();
+    for entity in falling {
+        ReleasePooled(entity).apply(world);
+    }
+}
+
+/* =======================
+   WARM REBOOT
+======================= */
+
+type RebootCleanup<'w, 's> = Query<
+    'w,
+    's,
+    Entity,
+    Or<(
+        With<GameOverOverlay>,
+        With<Present>,
+        With<Fan>,
+        With<CorruptedBit>,
+        With<GrinchElf>,
+        With<Coal>,
+    )>,
+>;
+
+fn warm_reboot(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    checkpoint: Res<Checkpoint>,
+    mut round: Round,
+    mut commands: Commands,
+    mut scores: ResMut<HighScores>,
+    cleanup: RebootCleanup,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyC)
+        || *round.state != GameState::Crashed
+        || !config.lives
+        || round.stats.reboots_left() == 0
+    {
+        return;
+    }
+
+    // 🏆 The run isn't over after all
+    if round.recorded.0 {
+        scores.retract(HighScore {
+            score: round.data.score,
+            max_combo: round.stats.max_combo,
+            continues: round.stats.continues_used,
+        });
+        round.recorded.0 = false;
+    }
+
+    // 💾 Restore checkpoint snapshot
+    round.data.score = checkpoint.score;
+    round.data.heat = 0.0;
+    round.data.speed_multiplier = 1.0;
+    round.data.overloading = false;
+    round.difficulty.level = checkpoint.level;
+    round.difficulty.time_alive = checkpoint.time_alive;
+
+    round.stats.continues_used += 1;
+    round.stats.crash_cause = None;
+    *round.combo = Combo::default();
+    round.thaw();
+
+    // 📦 Current quota phase starts over
+    round.quota.timer.reset();
+    round.quota.delivered_at_start = round.stats.presents_collected;
+    round.quota.missed_at_start = round.stats.presents_missed;
+
+    // 🛡 Fresh lives + a moment of safety
+    *round.lives = Lives::default();
+    round.lives.invulnerable.reset();
+
+    // 🧹 Remove overlay + clear the screen so nothing hits instantly
+    for e in cleanup.iter() {
+        commands.add(ReleasePooled(e));
+    }
+
+    round.fade.alpha = 0.0;
+    round.played.0 = false;
+    *round.state = GameState::Playing;
+
+    if !round.headless.0 {
+        println!("♻ WARM REBOOT ({} LEFT)", round.stats.reboots_left());
+    }
+}
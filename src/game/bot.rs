@@ -0,0 +1,233 @@
+//! Heuristic bot player.
+//!
+//! Reads the same [`Snapshot`] a simulation caller gets and answers with
+//! [`Actions`], so it drives Santa through exactly the path the keyboard does.
+//! It dodges the bit about to land on it, chases presents, overclocks while
+//! heat is low and goes for fans once heat is high.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::sim::{ObjectKind, SimObject, Snapshot};
+use super::{Actions, InputSource};
+
+// Santa's top speed with the stock tuning and the x clamp in `player_movement`
+const SANTA_SPEED: f32 = 300.0;
+const WALL_X: f32 = 320.0;
+// Roughly how far above or below Santa's centre a falling hitbox still touches his
+const REACH_Y: f32 = 45.0;
+
+/// Bot tuning. `novice`, `casual` and `expert` cover a rough range of skill.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct BotProfile {
+    /// Overclock while heat is below this.
+    pub overclock_below: f32,
+    /// Chase fans (and stop overclocking) once heat is above this.
+    pub fan_above: f32,
+    /// Hazards landing closer than this (px, horizontally) get dodged.
+    pub dodge_width: f32,
+    /// How far above Santa (px) the bot watches for hazards.
+    pub lookahead: f32,
+    /// Seconds between decisions; the bot holds its last input in between.
+    pub reaction: f32,
+    /// Dash out of the way when a hit is this close (seconds); 0 never dashes.
+    pub dash_within: f32,
+}
+
+impl Default for BotProfile {
+    fn default() -> Self {
+        Self::CASUAL
+    }
+}
+
+impl BotProfile {
+    pub const NOVICE: Self = Self {
+        overclock_below: 35.0,
+        fan_above: 75.0,
+        dodge_width: 44.0,
+        lookahead: 120.0,
+        reaction: 0.3,
+        dash_within: 0.0,
+    };
+
+    pub const CASUAL: Self = Self {
+        overclock_below: 55.0,
+        fan_above: 70.0,
+        dodge_width: 50.0,
+        lookahead: 180.0,
+        reaction: 0.15,
+        dash_within: 0.0,
+    };
+
+    pub const EXPERT: Self = Self {
+        overclock_below: 75.0,
+        fan_above: 80.0,
+        dodge_width: 58.0,
+        lookahead: 260.0,
+        reaction: 0.05,
+        dash_within: 0.2,
+    };
+
+    /// A preset by name (`novice`, `casual`, `expert`).
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "novice" => Some(Self::NOVICE),
+            "casual" => Some(Self::CASUAL),
+            "expert" => Some(Self::EXPERT),
+            _ => None,
+        }
+    }
+
+    /// One decision for the current frame, ignoring reaction time.
+    pub fn decide(&self, snapshot: &Snapshot) -> Actions {
+        let santa = snapshot.santa;
+        let hot = snapshot.heat > self.fan_above;
+
+        // 💥 Where each hazard in view crosses Santa's row, and when
+        let hazards: Vec<(f32, f32, f32)> = snapshot
+            .objects
+            .iter()
+            .filter(|o| match o.kind {
+                ObjectKind::Bit => true,
+                // Overclocked Santa debugs coal for points instead
+                ObjectKind::Coal => !snapshot.overclocking,
+                _ => false,
+            })
+            .filter(|o| o.position.y - santa.y < self.lookahead)
+            .filter_map(|o| crossing(o, santa))
+            .collect();
+
+        // Seconds until the first hit if Santa keeps moving in `direction`
+        let first_hit = |direction: f32| {
+            let gap =
+                |x: f32, t: f32| x - (santa.x + direction * SANTA_SPEED * t).clamp(-WALL_X, WALL_X);
+            hazards
+                .iter()
+                .filter(|(x, enter, leave)| {
+                    let (first, last) = (gap(*x, *enter), gap(*x, *leave));
+                    first.abs() < self.dodge_width
+                        || last.abs() < self.dodge_width
+                        || first.signum() != last.signum()
+                })
+                .map(|(_, enter, _)| *enter)
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        // 🎁 Head for the most wanted thing Santa can still reach
+        let wanted = |kind: ObjectKind| match kind {
+            ObjectKind::Fan => hot,
+            ObjectKind::Present | ObjectKind::GoldenPresent => true,
+            _ => false,
+        };
+        let target = snapshot
+            .objects
+            .iter()
+            .filter(|o| wanted(o.kind))
+            .filter_map(|o| crossing(o, santa).map(|(x, t, _)| (o.kind, x, t)))
+            .filter(|(_, x, t)| (x - santa.x).abs() <= SANTA_SPEED * t + 20.0)
+            .min_by(|a, b| {
+                let rank = |kind: ObjectKind| match kind {
+                    ObjectKind::Fan => 0,
+                    ObjectKind::GoldenPresent => 1,
+                    _ => 2,
+                };
+                rank(a.0).cmp(&rank(b.0)).then(a.2.total_cmp(&b.2))
+            });
+        let preferred = match target {
+            Some((_, x, _)) if (x - santa.x).abs() > 6.0 => (x - santa.x).signum(),
+            _ => 0.0,
+        };
+
+        // 🛡 Keep the plan unless it runs into something, else any safe way
+        let (direction, hit_in) = [preferred, 0.0, -1.0, 1.0]
+            .into_iter()
+            .map(|direction| (direction, first_hit(direction)))
+            .find(|(_, hit_in)| hit_in.is_infinite())
+            .unwrap_or_else(|| {
+                // Boxed in: run from the soonest hazard, away from the wall
+                let (x, enter, _) = hazards
+                    .iter()
+                    .copied()
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .unwrap_or_default();
+                let mut away = if santa.x >= x { 1.0 } else { -1.0 };
+                if (santa.x + away * self.dodge_width).abs() > WALL_X {
+                    away = -away;
+                }
+                (away, enter)
+            });
+
+        Actions {
+            movement: Vec2::new(direction, 0.0),
+            // 🧊 Running hot: stop overclocking until a fan brings heat down
+            overclock: snapshot.heat < self.overclock_below && !hot,
+            // ⚡ Dash i-frames carry Santa through a hit that can't be dodged
+            dash: snapshot.dash_ready && hit_in < self.dash_within && snapshot.heat < 85.0,
+        }
+    }
+}
+
+// When a falling object is level with Santa's hitbox (seconds from now, in
+// and out) and its x by then
+fn crossing(object: &SimObject, santa: Vec2) -> Option<(f32, f32, f32)> {
+    let above = object.position.y - santa.y - REACH_Y;
+    let fall = (-object.velocity.y).max(60.0);
+    let leave = (above + 2.0 * REACH_Y) / fall;
+    if leave < 0.0 {
+        return None; // already below Santa
+    }
+
+    let enter = above.max(0.0) / fall;
+    let x = (object.position.x + object.velocity.x * enter).clamp(-WALL_X, WALL_X);
+    Some((x, enter, leave))
+}
+
+/// A [`BotProfile`] plus the input it is holding between decisions.
+pub struct Bot {
+    pub profile: BotProfile,
+    since_decision: f32,
+    held: Actions,
+}
+
+impl Bot {
+    pub fn new(profile: BotProfile) -> Self {
+        Self {
+            profile,
+            since_decision: f32::INFINITY,
+            held: Actions::default(),
+        }
+    }
+
+    /// Input for a frame `dt` seconds after the previous one.
+    pub fn act(&mut self, snapshot: &Snapshot, dt: f32) -> Actions {
+        self.since_decision += dt;
+        if self.since_decision >= self.profile.reaction {
+            self.since_decision = 0.0;
+            self.held = self.profile.decide(snapshot);
+            return self.held;
+        }
+
+        // A dash is a single press, not something to hold
+        Actions {
+            dash: false,
+            ..self.held
+        }
+    }
+}
+
+pub(super) fn bot_actions(
+    source: Res<InputSource>,
+    time: Res<Time>,
+    snapshot: Res<Snapshot>,
+    mut bot: Local<Option<Bot>>,
+    mut actions: ResMut<Actions>,
+) {
+    let InputSource::Bot(profile) = *source else {
+        return;
+    };
+
+    let bot = bot.get_or_insert_with(|| Bot::new(profile));
+    bot.profile = profile;
+    *actions = bot.act(&snapshot, time.delta_seconds());
+}
@@ -0,0 +1,280 @@
+//! Gym-style environment over the headless [`Simulation`], for training agents.
+//!
+//! ```ignore
+//! let mut env = Env::new();
+//! let mut observation = env.reset(7);
+//! loop {
+//!     let action = policy(&observation);
+//!     let (next, reward, done, info) = env.step(action);
+//!     if done {
+//!         break;
+//!     }
+//!     observation = next;
+//! }
+//! ```
+//!
+//! One step is one 1/60 s frame. Nothing is rendered, so an episode runs as
+//! fast as the gameplay systems do (`cargo bench --bench gym` checks the rate).
+
+use bevy::math::Vec2;
+
+use super::sim::{ObjectKind, SimObject, Simulation, Snapshot, FRAME};
+use super::{Actions, CrashCause};
+
+/// Slots per object kind in an [`Observation`]; the nearest ones fill first.
+pub const NEAREST: usize = 4;
+
+/// What the agent does for one frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Action {
+    /// -1 (left) ..= 1 (right).
+    pub movement: f32,
+    pub overclock: bool,
+    /// Starts a dash if it's off cooldown.
+    pub dash: bool,
+}
+
+impl Action {
+    /// Size of the discrete action space used by [`from_index`](Self::from_index).
+    pub const COUNT: usize = 12;
+
+    /// Left / stay / right × overclock × dash, for agents with discrete actions.
+    pub fn from_index(index: usize) -> Self {
+        let index = index % Self::COUNT;
+        Self {
+            movement: (index % 3) as f32 - 1.0,
+            overclock: (index / 3) % 2 == 1,
+            dash: index / 6 == 1,
+        }
+    }
+}
+
+impl From<Actions> for Action {
+    fn from(actions: Actions) -> Self {
+        Self {
+            movement: actions.movement.x,
+            overclock: actions.overclock,
+            dash: actions.dash,
+        }
+    }
+}
+
+impl From<Action> for Actions {
+    fn from(action: Action) -> Self {
+        Self {
+            movement: Vec2::new(action.movement.clamp(-1.0, 1.0), 0.0),
+            overclock: action.overclock,
+            dash: action.dash,
+        }
+    }
+}
+
+/// One nearby object relative to Santa (px and px/s); empty slots are all zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Slot {
+    pub present: bool,
+    pub offset: Vec2,
+    pub velocity: Vec2,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Observation {
+    /// -320 ..= 320.
+    pub santa_x: f32,
+    pub santa_velocity_x: f32,
+    /// 0 ..= 100.
+    pub heat: f32,
+    pub difficulty: f32,
+    pub overclocking: bool,
+    pub dash_ready: bool,
+    /// Presents (golden ones too), nearest first.
+    pub presents: [Slot; NEAREST],
+    pub fans: [Slot; NEAREST],
+    pub bits: [Slot; NEAREST],
+}
+
+impl Observation {
+    /// Length of [`features`](Self::features).
+    pub const LEN: usize = 6 + 3 * NEAREST * 5;
+
+    fn new(snapshot: &Snapshot) -> Self {
+        let nearest = |wanted: &dyn Fn(ObjectKind) -> bool| {
+            let mut objects: Vec<&SimObject> = snapshot
+                .objects
+                .iter()
+                .filter(|object| wanted(object.kind))
+                .collect();
+            objects.sort_by(|a, b| {
+                let distance =
+                    |object: &SimObject| object.position.distance_squared(snapshot.santa);
+                distance(a).total_cmp(&distance(b))
+            });
+
+            let mut slots = [Slot::default(); NEAREST];
+            for (slot, object) in slots.iter_mut().zip(objects) {
+                *slot = Slot {
+                    present: true,
+                    offset: object.position - snapshot.santa,
+                    velocity: object.velocity,
+                };
+            }
+            slots
+        };
+
+        Self {
+            santa_x: snapshot.santa.x,
+            santa_velocity_x: snapshot.santa_velocity.x,
+            heat: snapshot.heat,
+            difficulty: snapshot.difficulty,
+            overclocking: snapshot.overclocking,
+            dash_ready: snapshot.dash_ready,
+            presents: nearest(&|kind| {
+                matches!(kind, ObjectKind::Present | ObjectKind::GoldenPresent)
+            }),
+            fans: nearest(&|kind| kind == ObjectKind::Fan),
+            bits: nearest(&|kind| kind == ObjectKind::Bit),
+        }
+    }
+
+    /// Flat, roughly unit-scaled vector for a network input: Santa's state,
+    /// then presents, fans and bits as `[present, dx, dy, vx, vy]` per slot.
+    pub fn features(&self) -> Vec<f32> {
+        const POSITION: f32 = 1.0 / 320.0;
+        const SPEED: f32 = 1.0 / 600.0;
+
+        let mut features = Vec::with_capacity(Self::LEN);
+        features.extend([
+            self.santa_x * POSITION,
+            self.santa_velocity_x * SPEED,
+            self.heat / 100.0,
+            self.difficulty,
+            f32::from(self.overclocking),
+            f32::from(self.dash_ready),
+        ]);
+        for slot in self.presents.iter().chain(&self.fans).chain(&self.bits) {
+            features.extend([
+                f32::from(slot.present),
+                slot.offset.x * POSITION,
+                slot.offset.y * POSITION,
+                slot.velocity.x * SPEED,
+                slot.velocity.y * SPEED,
+            ]);
+        }
+        features
+    }
+}
+
+/// Reward shaping. Each step pays `per_point` for score gained plus
+/// `per_second` for staying alive; crashing costs `crash` once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rewards {
+    pub per_point: f32,
+    pub per_second: f32,
+    pub crash: f32,
+}
+
+impl Default for Rewards {
+    fn default() -> Self {
+        Self {
+            per_point: 0.01,
+            per_second: 1.0,
+            crash: -10.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Info {
+    pub frame: u64,
+    pub time_alive: f32,
+    pub score: u32,
+    /// Set once the episode ended in a crash.
+    pub crash_cause: Option<CrashCause>,
+    /// The episode hit `max_seconds` rather than crashing.
+    pub truncated: bool,
+}
+
+pub struct Env {
+    sim: Option<Simulation>,
+    rewards: Rewards,
+    max_seconds: f32,
+    last_score: u32,
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Env {
+    /// Episodes end on a crash or after ten minutes of game time.
+    pub fn new() -> Self {
+        Self {
+            sim: None,
+            rewards: Rewards::default(),
+            max_seconds: 600.0,
+            last_score: 0,
+        }
+    }
+
+    pub fn with_rewards(mut self, rewards: Rewards) -> Self {
+        self.rewards = rewards;
+        self
+    }
+
+    pub fn with_max_seconds(mut self, max_seconds: f32) -> Self {
+        self.max_seconds = max_seconds;
+        self
+    }
+
+    /// Starts a new episode on `seed`.
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        let sim = self.sim.insert(Simulation::new(seed));
+        self.last_score = sim.snapshot().score;
+        Observation::new(sim.snapshot())
+    }
+
+    /// Plays one frame. Panics before the first [`reset`](Self::reset).
+    pub fn step(&mut self, action: Action) -> (Observation, f32, bool, Info) {
+        let sim = self.sim.as_mut().expect("Env::reset before Env::step");
+        let was_crashed = sim.is_crashed();
+        if !was_crashed {
+            sim.set_actions(action.into());
+            sim.step();
+        }
+
+        let snapshot = sim.snapshot();
+        let crashed = snapshot.crashed;
+        let truncated = !crashed && snapshot.time_alive >= self.max_seconds;
+
+        let mut reward =
+            snapshot.score.saturating_sub(self.last_score) as f32 * self.rewards.per_point;
+        if crashed && !was_crashed {
+            reward += self.rewards.crash;
+        } else if !crashed {
+            reward += self.rewards.per_second * FRAME;
+        }
+        self.last_score = snapshot.score;
+
+        let info = Info {
+            frame: snapshot.frame,
+            time_alive: snapshot.time_alive,
+            score: snapshot.score,
+            crash_cause: sim.crash_cause(),
+            truncated,
+        };
+        (
+            Observation::new(snapshot),
+            reward,
+            crashed || truncated,
+            info,
+        )
+    }
+
+    /// The full frame behind the last observation, e.g. for a
+    /// [`Bot`](super::bot::Bot) recording expert episodes.
+    pub fn snapshot(&self) -> Option<&Snapshot> {
+        self.sim.as_ref().map(Simulation::snapshot)
+    }
+}
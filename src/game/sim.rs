@@ -0,0 +1,317 @@
+//! Headless simulation: the full game without a window, stepped by hand.
+//!
+//! [`Simulation`] builds the same [`GamePlugin`] the binary runs on top of
+//! `MinimalPlugins`, advances a fixed 1/60 s per step and starts straight into
+//! a round. Input comes through [`Actions`], either set by the caller or
+//! produced by a [`Bot`](super::bot::Bot). After every frame a [`Snapshot`]
+//! describes what is on screen.
+//!
+//! Gameplay runs on the fixed tick in one chained order, so a seed replays
+//! frame for frame, in this process or the next launch.
+
+use bevy::app::Plugins;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioSource;
+use bevy::ecs::schedule::{ExecutorKind, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use bevy::text::Font;
+use bevy::time::TimeUpdateStrategy;
+use bevy::utils::HashMap;
+
+use super::bot::BotProfile;
+use super::{
+    Actions, Coal, Collider, CollisionLayer, CorruptedBit, CrashCause, Dash, Difficulty, Fan,
+    GameConfig, GameData, GamePlugin, GameRng, GameState, GrinchBoss, GrinchElf, InputSource,
+    Player, Pooled, Present, RunStats, Snowflake, Velocity, Weather,
+};
+
+/// Seconds per simulated frame. Gameplay tuned per frame assumes 60 Hz.
+pub const FRAME: f32 = 1.0 / 60.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ObjectKind {
+    Present,
+    GoldenPresent,
+    Fan,
+    Bit,
+    Coal,
+    Elf,
+    Boss,
+}
+
+/// Something in play, in world pixels (Santa stands at y = -250).
+#[derive(Clone, Copy, Debug)]
+pub struct SimObject {
+    pub kind: ObjectKind,
+    pub position: Vec2,
+    /// px/s, measured over the last frame (zero on the frame it appears).
+    pub velocity: Vec2,
+}
+
+/// The game as a bot or a test sees it, refreshed at the end of every frame.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct Snapshot {
+    pub frame: u64,
+    pub santa: Vec2,
+    pub santa_velocity: Vec2,
+    pub heat: f32,
+    pub difficulty: f32,
+    pub time_alive: f32,
+    pub score: u32,
+    pub overclocking: bool,
+    pub dash_ready: bool,
+    pub crashed: bool,
+    pub crash_cause: Option<CrashCause>,
+    pub objects: Vec<SimObject>,
+    /// Snowflake positions; scenery, nothing collides with them.
+    pub snow: Vec<Vec2>,
+}
+
+type SimObjects<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        Option<&'static Present>,
+        Option<&'static Collider>,
+        Option<&'static Pooled>,
+        Has<Fan>,
+        Has<CorruptedBit>,
+        Has<Coal>,
+        Has<GrinchBoss>,
+    ),
+    Or<(
+        With<Present>,
+        With<Fan>,
+        With<CorruptedBit>,
+        With<Coal>,
+        With<GrinchElf>,
+        With<GrinchBoss>,
+    )>,
+>;
+
+// 📋 The numbers a snapshot reports besides what's on screen
+#[derive(SystemParam)]
+pub(super) struct Readout<'w> {
+    time: Res<'w, Time>,
+    state: Res<'w, GameState>,
+    data: Res<'w, GameData>,
+    difficulty: Res<'w, Difficulty>,
+    dash: Res<'w, Dash>,
+    stats: Res<'w, RunStats>,
+}
+
+pub(super) fn capture_snapshot(
+    readout: Readout,
+    mut snapshot: ResMut<Snapshot>,
+    mut previous: Local<HashMap<Entity, Vec2>>,
+    santa: Query<(&Transform, &Velocity), With<Player>>,
+    objects: SimObjects,
+    snow: Query<(&Transform, &Pooled), With<Snowflake>>,
+) {
+    let dt = readout.time.delta_seconds();
+    let mut seen = HashMap::default();
+
+    snapshot.objects.clear();
+    for (entity, transform, present, collider, pooled, fan, bit, coal, boss) in objects.iter() {
+        // 👻 Parked in a pool or already caught / stolen
+        if pooled.is_some_and(|pooled| !pooled.active)
+            || collider.is_some_and(|collider| collider.layer == CollisionLayer::Ghost)
+        {
+            continue;
+        }
+
+        let kind = match (present, fan, bit, coal, boss) {
+            (Some(present), ..) if present.golden => ObjectKind::GoldenPresent,
+            (Some(_), ..) => ObjectKind::Present,
+            (_, true, ..) => ObjectKind::Fan,
+            (_, _, true, ..) => ObjectKind::Bit,
+            (_, _, _, true, _) => ObjectKind::Coal,
+            (.., true) => ObjectKind::Boss,
+            _ => ObjectKind::Elf,
+        };
+        let position = transform.translation.truncate();
+        let velocity = match previous.get(&entity) {
+            Some(last) if dt > 0.0 => (position - *last) / dt,
+            _ => Vec2::ZERO,
+        };
+
+        seen.insert(entity, position);
+        snapshot.objects.push(SimObject {
+            kind,
+            position,
+            velocity,
+        });
+    }
+    *previous = seen;
+
+    snapshot.snow.clear();
+    snapshot.snow.extend(
+        snow.iter()
+            .filter(|(_, pooled)| pooled.active)
+            .map(|(transform, _)| transform.translation.truncate()),
+    );
+
+    if let Ok((transform, velocity)) = santa.get_single() {
+        snapshot.santa = transform.translation.truncate();
+        snapshot.santa_velocity = velocity.0;
+    }
+    snapshot.frame += 1;
+    snapshot.heat = readout.data.heat;
+    snapshot.difficulty = readout.difficulty.level;
+    snapshot.time_alive = readout.difficulty.time_alive;
+    snapshot.score = readout.data.score;
+    snapshot.overclocking = readout.data.overloading;
+    snapshot.dash_ready = readout.dash.cooldown.finished() && !readout.dash.is_dashing();
+    snapshot.crashed = *readout.state == GameState::Crashed;
+    snapshot.crash_cause = readout.stats.crash_cause;
+}
+
+/// One headless game, stepped a frame at a time.
+pub struct Simulation {
+    app: App,
+}
+
+impl Simulation {
+    /// A round already in progress with the spawns and weather of `seed`.
+    /// Input is [`External`](Self::set_actions) until a bot is set.
+    pub fn new(seed: u64) -> Self {
+        Self::build(seed, None)
+    }
+
+    /// Like [`new`](Self::new), with `config` (the text of a `config.ron`)
+    /// instead of `assets/config.ron`.
+    pub fn with_config(seed: u64, config: &str) -> Result<Self, String> {
+        let config: GameConfig = ron::from_str(config).map_err(|err| err.to_string())?;
+        Ok(Self::build(seed, Some(config)))
+    }
+
+    fn build(seed: u64, config: Option<GameConfig>) -> Self {
+        let mut app = headless_app(seed, config, ());
+        app.insert_resource(InputSource::External);
+
+        // ▶ Start from the menu exactly like a player would. The first update
+        // runs `Startup` with a zero clock, so ENTER waits for a real frame
+        let mut sim = Self { app };
+        sim.step();
+        sim.press(KeyCode::Enter);
+        sim
+    }
+
+    /// Hands input to a bot from the next frame on.
+    pub fn set_bot(&mut self, profile: BotProfile) {
+        self.app.insert_resource(InputSource::Bot(profile));
+    }
+
+    /// Input from the next frame on (ignored while a bot is driving).
+    /// Movement and overclock are held until changed; a dash is used up by
+    /// the frame that reads it.
+    pub fn set_actions(&mut self, actions: Actions) {
+        self.app.insert_resource(actions);
+    }
+
+    /// Advances one frame.
+    pub fn step(&mut self) {
+        self.app.update();
+    }
+
+    /// Steps until Santa crashes or `seconds` of game time have passed.
+    pub fn run_until_crash(&mut self, seconds: f32) -> &Snapshot {
+        let frames = (seconds / FRAME).ceil() as u64;
+        for _ in 0..frames {
+            if self.is_crashed() {
+                break;
+            }
+            self.step();
+        }
+        self.snapshot()
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        self.app.world.resource::<Snapshot>()
+    }
+
+    pub fn is_crashed(&self) -> bool {
+        self.snapshot().crashed
+    }
+
+    /// Why the round ended, once it has.
+    pub fn crash_cause(&self) -> Option<CrashCause> {
+        self.app.world.resource::<RunStats>().crash_cause
+    }
+
+    /// Reboots after a crash (the R key); does nothing mid-round.
+    pub fn restart(&mut self) {
+        self.press(KeyCode::KeyR);
+    }
+
+    // A one-frame key press, for menu-style input that isn't an action
+    fn press(&mut self, key: KeyCode) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            send_key(&mut self.app, key, state);
+            self.step();
+        }
+    }
+}
+
+/// The game on `MinimalPlugins`, still in the menu: one thread, `FRAME`
+/// seconds per update and `seed` for spawns and weather. `config` replaces
+/// `assets/config.ron`; `extra` plugins go in before the game's.
+pub(super) fn headless_app<M>(
+    seed: u64,
+    config: Option<GameConfig>,
+    extra: impl Plugins<M>,
+) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin))
+        .init_asset::<Image>()
+        .init_asset::<Font>()
+        .init_asset::<AudioSource>()
+        .add_plugins(extra)
+        .add_plugins(GamePlugin { headless: true });
+
+    // ⏱ Exactly one fixed tick per update
+    let tick = app.world.resource::<Time<Fixed>>().timestep();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(tick));
+
+    // 🎲 One thread, so the order is the same every frame and every run
+    for label in [
+        PreStartup.intern(),
+        Startup.intern(),
+        PostStartup.intern(),
+        First.intern(),
+        PreUpdate.intern(),
+        FixedUpdate.intern(),
+        Update.intern(),
+        PostUpdate.intern(),
+        Last.intern(),
+    ] {
+        app.edit_schedule(label, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+    }
+
+    app.insert_resource(GameRng::new(Some(seed)))
+        .insert_resource(Weather::new(seed));
+    if let Some(config) = config {
+        app.insert_resource(config);
+    }
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Queues a key event for the next update. `InputPlugin` turns it into
+/// `ButtonInput<KeyCode>`, so it goes through the same path as a real key.
+pub(super) fn send_key(app: &mut App, key: KeyCode, state: ButtonState) {
+    app.world.send_event(KeyboardInput {
+        key_code: key,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
@@ -0,0 +1,523 @@
+//! Gameplay tests on a headless app: real plugins, manual 1/60 s frames and
+//! key events through `InputPlugin`. No window or GPU needed.
+
+use bevy::input::ButtonState;
+
+use super::sim::{headless_app, send_key, FRAME};
+use super::*;
+
+const SEED: u64 = 2512;
+
+struct Harness {
+    app: App,
+}
+
+impl Harness {
+    // Default tuning, keyboard input, already past the menu
+    fn new() -> Self {
+        Self::playing(GameConfig::default())
+    }
+
+    fn playing(config: GameConfig) -> Self {
+        let mut harness = Self {
+            app: headless_app(SEED, Some(config), ()),
+        };
+        harness.tap(KeyCode::Enter);
+        assert!(
+            harness.state() == &GameState::Playing,
+            "ENTER starts a round"
+        );
+        harness
+    }
+
+    fn step(&mut self) {
+        self.app.update();
+    }
+
+    fn hold(&mut self, key: KeyCode) {
+        send_key(&mut self.app, key, ButtonState::Pressed);
+    }
+
+    fn release(&mut self, key: KeyCode) {
+        send_key(&mut self.app, key, ButtonState::Released);
+    }
+
+    // Pressed for one frame, released on the next
+    fn tap(&mut self, key: KeyCode) {
+        self.hold(key);
+        self.step();
+        self.release(key);
+        self.step();
+    }
+
+    // Steps until `done` holds, for at most `seconds`; the game time it took
+    fn run_until(&mut self, seconds: f32, done: impl Fn(&World) -> bool) -> Option<f32> {
+        let frames = (seconds / FRAME).ceil() as u32;
+        for frame in 1..=frames {
+            self.step();
+            if done(&self.app.world) {
+                return Some(frame as f32 * FRAME);
+            }
+        }
+        None
+    }
+
+    fn resource<R: Resource>(&self) -> &R {
+        self.app.world.resource::<R>()
+    }
+
+    fn state(&self) -> &GameState {
+        self.resource::<GameState>()
+    }
+
+    fn santa(&mut self) -> Vec3 {
+        self.app
+            .world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(&self.app.world)
+            .translation
+    }
+
+    // Drops a pooled object at `position`, as its spawner would
+    fn spawn(&mut self, kind: PoolKind, position: Vec3) {
+        let transform = Transform::from_translation(position).with_scale(Vec3::splat(0.8));
+        match kind {
+            PoolKind::CorruptedBit => SpawnPooled::new(kind, transform)
+                .with(BitDrift(0.0))
+                .apply(&mut self.app.world),
+            _ => SpawnPooled::new(kind, transform).apply(&mut self.app.world),
+        }
+    }
+
+    // Active (not pooled away) entities with component `C`
+    fn count<C: Component>(&mut self) -> usize {
+        self.app
+            .world
+            .query_filtered::<Option<&Pooled>, With<C>>()
+            .iter(&self.app.world)
+            .filter(|pooled| pooled.is_none_or(|pooled| pooled.active))
+            .count()
+    }
+}
+
+fn crashed(world: &World) -> bool {
+    *world.resource::<GameState>() == GameState::Crashed
+}
+
+#[test]
+fn overclocking_to_full_heat_crashes() {
+    let mut game = Harness::new();
+
+    // +0.8 heat a frame: 100 in about 2.1 s, before the first bit lands
+    game.hold(KeyCode::Space);
+    let took = game.run_until(3.0, crashed).expect("never overheated");
+
+    assert!((2.0..2.3).contains(&took), "overheated after {took} s");
+    assert_eq!(game.resource::<GameData>().heat, 100.0);
+    assert_eq!(
+        game.resource::<RunStats>().crash_cause,
+        Some(CrashCause::Overheat)
+    );
+}
+
+#[test]
+fn corrupted_bit_freezes_then_crashes() {
+    let mut game = Harness::new();
+    let santa = game.santa();
+    game.spawn(PoolKind::CorruptedBit, santa);
+
+    game.run_until(0.1, |world| world.resource::<HitFreeze>().active)
+        .expect("bit on Santa never hit");
+    assert!(game.state() == &GameState::Playing, "freeze comes first");
+
+    let took = game.run_until(1.0, crashed).expect("freeze never ended");
+    assert!(
+        (took - 0.15).abs() <= FRAME + 1e-4,
+        "crashed {took} s after the hit"
+    );
+    assert!(!game.resource::<HitFreeze>().active);
+    assert_eq!(
+        game.resource::<RunStats>().crash_cause,
+        Some(CrashCause::Corrupted)
+    );
+}
+
+#[test]
+fn warm_reboot_thaws_a_pending_freeze() {
+    let mut game = Harness::playing(GameConfig {
+        lives: true,
+        ..default()
+    });
+    game.app.world.resource_mut::<Lives>().remaining = 1;
+    let santa = game.santa();
+    game.spawn(PoolKind::CorruptedBit, santa);
+    game.run_until(0.1, |world| world.resource::<HitFreeze>().active)
+        .expect("bit on Santa never hit");
+
+    // Overheat before the freeze runs out
+    game.app.world.resource_mut::<GameData>().heat = 99.5;
+    game.hold(KeyCode::Space);
+    game.run_until(0.1, crashed).expect("never overheated");
+    game.release(KeyCode::Space);
+    assert_eq!(
+        game.resource::<RunStats>().crash_cause,
+        Some(CrashCause::Overheat)
+    );
+
+    game.tap(KeyCode::KeyC);
+    assert!(!game.resource::<HitFreeze>().active);
+    assert_eq!(game.run_until(1.0, crashed), None, "continue crashed again");
+}
+
+#[test]
+fn fan_pickup_cools_santa() {
+    let mut game = Harness::new();
+    game.app.world.resource_mut::<GameData>().heat = 60.0;
+    let santa = game.santa();
+    game.spawn(PoolKind::Fan, santa);
+
+    game.run_until(0.1, |world| {
+        world.resource::<RunStats>().fans_collected == 1
+    })
+    .expect("fan on Santa never collected");
+
+    // -25 for the fan, a little passive cooling on top
+    let heat = game.resource::<GameData>().heat;
+    assert!((30.0..=35.0).contains(&heat), "heat {heat} after a fan");
+    assert_eq!(game.count::<Fan>(), 0);
+}
+
+// Seconds on each spawn timer
+fn round_clocks(game: &Harness) -> [f32; 6] {
+    [
+        game.resource::<SpawnTimer>().0.elapsed_secs(),
+        game.resource::<FanSpawnTimer>().0.elapsed_secs(),
+        game.resource::<CorruptedSpawnTimer>().0.elapsed_secs(),
+        game.resource::<CorruptedBitSpawnTimer>().0.elapsed_secs(),
+        game.resource::<ElfSpawnTimer>().0.elapsed_secs(),
+        game.resource::<SnowSpawnTimer>().0.elapsed_secs(),
+    ]
+}
+
+#[test]
+fn restart_resets_the_round() {
+    let mut game = Harness::new();
+
+    // Something of everything on screen, then overheat
+    for x in [-250.0, 0.0, 250.0] {
+        game.spawn(PoolKind::Present, Vec3::new(x, 250.0, 0.0));
+        game.spawn(PoolKind::Fan, Vec3::new(x, 200.0, 0.0));
+        game.spawn(PoolKind::CorruptedBit, Vec3::new(x, 300.0, 0.0));
+    }
+    game.hold(KeyCode::Space);
+    game.run_until(3.0, crashed).expect("never overheated");
+    game.release(KeyCode::Space);
+    game.run_until(1.0, |world| world.resource::<CrashSoundPlayed>().0)
+        .expect("no crash sound");
+    assert!(game.count::<GameOverOverlay>() > 0);
+    assert!(game.count::<Present>() + game.count::<Fan>() + game.count::<CorruptedBit>() > 0);
+    assert!(round_clocks(&game).iter().any(|elapsed| *elapsed > 1.0));
+    // As a bit hit in the last frames before the overheat would leave it
+    game.app.world.resource_mut::<HitFreeze>().active = true;
+
+    game.tap(KeyCode::KeyR);
+
+    assert!(game.state() == &GameState::Playing);
+    let data = game.resource::<GameData>();
+    assert_eq!(data.score, 0);
+    assert_eq!(data.heat, 0.0);
+    assert_eq!(data.speed_multiplier, 1.0);
+    assert!(!data.overloading);
+
+    // Two frames (press + release) of the new round have already run
+    let difficulty = game.resource::<Difficulty>();
+    assert!(difficulty.level < 1.01, "level {}", difficulty.level);
+    assert!(difficulty.time_alive <= 2.0 * FRAME + 1e-4);
+    for elapsed in round_clocks(&game) {
+        assert!(elapsed <= 2.0 * FRAME + 1e-4, "spawn timer at {elapsed} s");
+    }
+    let time_scale = game.resource::<TimeScale>().value;
+    assert!(time_scale < 0.1, "time scale {time_scale} two frames in");
+    assert!(!game.resource::<HitFreeze>().active);
+
+    assert!(!game.resource::<CrashSoundPlayed>().0);
+    assert_eq!(game.resource::<RunStats>().crash_cause, None);
+    assert_eq!(game.count::<GameOverOverlay>(), 0);
+    assert_eq!(game.count::<ScorePopup>(), 0);
+    assert_eq!(game.count::<Present>(), 0);
+    assert_eq!(game.count::<Fan>(), 0);
+    assert_eq!(game.count::<CorruptedBit>(), 0);
+    assert_eq!(game.count::<GrinchElf>(), 0);
+    assert_eq!(game.count::<Coal>(), 0);
+    assert_eq!(game.count::<GrinchBoss>(), 0);
+}
+
+#[test]
+fn restart_replays_a_fresh_round() {
+    use super::sim::Simulation;
+    use super::text::TextFrame;
+
+    // Drift right and overheat, so little of the first run matches a fresh one
+    let mut restarted = Simulation::new(SEED);
+    restarted.set_actions(Actions {
+        movement: Vec2::X,
+        overclock: true,
+        dash: false,
+    });
+    restarted.run_until_crash(10.0);
+    assert!(restarted.is_crashed(), "never overheated");
+    restarted.set_actions(Actions::default());
+    restarted.restart();
+
+    let mut fresh = Simulation::new(SEED);
+    // Past the first elf at 30 s
+    for frame in 0..1900 {
+        let (a, b) = (restarted.snapshot(), fresh.snapshot());
+        assert_eq!(
+            (a.score, a.heat, a.difficulty, a.crashed),
+            (b.score, b.heat, b.difficulty, b.crashed),
+            "frame {frame}"
+        );
+        let (a, b) = (TextFrame::render(a, 80, 24), TextFrame::render(b, 80, 24));
+        assert!(a == b, "frame {frame}, restarted:\n{a}fresh:\n{b}");
+        restarted.step();
+        fresh.step();
+    }
+}
+
+/* =======================
+   UI LAYOUT SNAPSHOTS
+======================= */
+
+// 🖼 Everything `UiPlugin` needs to lay out nodes and measure text, minus the
+// renderer: a window entity (never opened), camera viewports and fonts.
+struct HeadlessUiPlugin;
+
+impl Plugin for HeadlessUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            WindowPlugin::default(),
+            TransformPlugin,
+            HierarchyPlugin,
+            bevy::render::camera::CameraPlugin,
+            bevy::text::TextPlugin,
+        ))
+        .init_asset::<Shader>()
+        .init_asset::<TextureAtlasLayout>()
+        .add_plugins(bevy::ui::UiPlugin);
+    }
+}
+
+const SCREEN: Vec2 = Vec2::new(1280.0, 720.0);
+
+impl Harness {
+    // In the menu with the HUD font loaded and laid out
+    fn with_ui(config: GameConfig) -> Self {
+        let mut harness = Self {
+            app: headless_app(SEED, Some(config), HeadlessUiPlugin),
+        };
+
+        // Fonts load on the IO task pool, outside the frame clock
+        for _ in 0..500 {
+            if !harness.resource::<Assets<Font>>().is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            harness.step();
+        }
+        assert!(
+            !harness.resource::<Assets<Font>>().is_empty(),
+            "font never loaded"
+        );
+        harness.step();
+        harness
+    }
+
+    // Every visible UI node as `x,y wxh "text" #rrggbbaa`, children indented
+    fn layout(&mut self) -> String {
+        let world = &mut self.app.world;
+        let mut roots: Vec<(Rect, Entity)> = world
+            .query_filtered::<(Entity, &Node, &GlobalTransform), Without<Parent>>()
+            .iter(world)
+            .map(|(entity, node, transform)| (node.logical_rect(transform), entity))
+            .collect();
+        roots.sort_by(|(a, _), (b, _)| {
+            (a.min.y, a.min.x, a.max.y, a.max.x)
+                .partial_cmp(&(b.min.y, b.min.x, b.max.y, b.max.x))
+                .unwrap()
+        });
+
+        let mut out = String::new();
+        for (_, root) in roots {
+            describe(world, root, 0, &mut out);
+        }
+        out
+    }
+
+    // Text nodes that stick out of the window
+    fn off_screen(&mut self) -> Vec<String> {
+        let world = &mut self.app.world;
+        let mut off = Vec::new();
+        for (entity, node, transform, text) in world
+            .query::<(Entity, &Node, &GlobalTransform, &Text)>()
+            .iter(world)
+        {
+            let rect = node.logical_rect(transform);
+            let shown = text.sections.iter().any(|s| !s.value.trim().is_empty());
+            let inside =
+                rect.min.cmpge(Vec2::splat(-0.5)).all() && rect.max.cmple(SCREEN + 0.5).all();
+            if shown && !inside && visible(world, entity) {
+                off.push(format!("{:?} at {rect:?}", sections(text)));
+            }
+        }
+        off
+    }
+}
+
+fn visible(world: &World, entity: Entity) -> bool {
+    let hidden = world.get::<Visibility>(entity) == Some(&Visibility::Hidden)
+        || world
+            .get::<Style>(entity)
+            .is_some_and(|style| style.display == Display::None);
+    !hidden
+        && world
+            .get::<Parent>(entity)
+            .is_none_or(|parent| visible(world, parent.get()))
+}
+
+fn sections(text: &Text) -> String {
+    text.sections.iter().map(|s| s.value.as_str()).collect()
+}
+
+fn describe(world: &World, entity: Entity, depth: usize, out: &mut String) {
+    let (Some(node), Some(transform)) = (
+        world.get::<Node>(entity),
+        world.get::<GlobalTransform>(entity),
+    ) else {
+        return;
+    };
+    if !visible(world, entity) {
+        return;
+    }
+
+    let rect = node.logical_rect(transform);
+    let mut line = format!(
+        "{:indent$}{:.0},{:.0} {:.0}x{:.0}",
+        "",
+        rect.min.x,
+        rect.min.y,
+        rect.width(),
+        rect.height(),
+        indent = depth * 2
+    );
+    if let Some(text) = world.get::<Text>(entity) {
+        for section in &text.sections {
+            let [r, g, b, a] = section.style.color.as_rgba_u8();
+            line.push_str(&format!(
+                " {:?} #{r:02x}{g:02x}{b:02x}{a:02x}",
+                section.value
+            ));
+        }
+    }
+    out.push_str(line.trim_end());
+    out.push('\n');
+
+    if let Some(children) = world.get::<Children>(entity) {
+        for child in children {
+            describe(world, *child, depth + 1, out);
+        }
+    }
+}
+
+// Compares with `src/game/snapshots/<name>.txt`; UPDATE_SNAPSHOTS=1 rewrites it
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = format!(
+        "{}/src/game/snapshots/{name}.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(std::path::Path::new(&path).parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("no snapshot {path}; run with UPDATE_SNAPSHOTS=1"));
+    if expected != actual {
+        let diff: Vec<String> = expected
+            .lines()
+            .map(Some)
+            .chain(std::iter::repeat(None))
+            .zip(actual.lines().map(Some).chain(std::iter::repeat(None)))
+            .take_while(|pair| pair != &(None, None))
+            .filter(|(old, new)| old != new)
+            .map(|(old, new)| format!("- {}\n+ {}", old.unwrap_or(""), new.unwrap_or("")))
+            .collect();
+        panic!(
+            "{name} layout changed (UPDATE_SNAPSHOTS=1 to accept):\n{}",
+            diff.join("\n")
+        );
+    }
+}
+
+fn assert_screen(game: &mut Harness, name: &str) {
+    let off = game.off_screen();
+    assert!(off.is_empty(), "{name}: text off screen: {off:#?}");
+    let layout = game.layout();
+    assert_snapshot(name, &layout);
+}
+
+#[test]
+fn menu_layout() {
+    let mut game = Harness::with_ui(GameConfig::default());
+    assert_screen(&mut game, "menu");
+
+    game.tap(KeyCode::KeyS);
+    assert_screen(&mut game, "menu_stats");
+}
+
+#[test]
+fn hud_layout() {
+    let mut game = Harness::with_ui(GameConfig::default());
+    game.tap(KeyCode::Enter);
+
+    // Heat text turns orange-red above 70; 0.4 a frame of cooling can't
+    // move either value across a whole percent or the threshold
+    for (name, heat) in [("hud", 40.9), ("hud_hot", 70.9)] {
+        game.app.world.resource_mut::<GameData>().heat = heat;
+        game.step();
+        assert_screen(&mut game, name);
+    }
+}
+
+#[test]
+fn crash_overlay_layout() {
+    for (name, lives) in [("crash_overlay", false), ("crash_overlay_lives", true)] {
+        let mut game = Harness::with_ui(GameConfig { lives, ..default() });
+        game.tap(KeyCode::Enter);
+        game.hold(KeyCode::Space);
+        game.run_until(3.0, crashed).expect("never overheated");
+        game.step();
+        game.step();
+        assert_screen(&mut game, name);
+    }
+}
+
+// Same as `terminal --seed 2512 --bot expert --dump 600 --size 80x24`, then
+// the crash banner once the bot goes down
+#[test]
+fn text_frame_layout() {
+    let mut sim = super::sim::Simulation::new(SEED);
+    sim.set_bot(bot::BotProfile::EXPERT);
+    for _ in 0..600 {
+        sim.step();
+    }
+    assert!(!sim.is_crashed(), "expert crashed in the first 10 s");
+    let frame = text::TextFrame::render(sim.snapshot(), 80, 24);
+    assert_snapshot("text_frame", &frame.to_string());
+
+    sim.run_until_crash(600.0);
+    assert!(sim.is_crashed(), "expert never crashed");
+    let frame = text::TextFrame::render(sim.snapshot(), 80, 24);
+    assert_snapshot("text_frame_crashed", &frame.to_string());
+}
@@ -0,0 +1,165 @@
+//! Text frames: a [`Snapshot`] drawn as a grid of characters.
+//!
+//! The terminal frontend (`--bin terminal`) puts these on screen, and since
+//! a frame is plain text, two frames can be diffed like any other file.
+//!
+//! ```text
+//!  <S>  Santa      #  present    $  golden present   @  fan
+//!   %   bit        c  coal       e  Grinch elf       G  Grinch boss
+//!   .   snow
+//! ```
+//!
+//! The top row is the HUD (score, heat bar, difficulty, overclock and dash),
+//! the rest is the playfield. A crashed round gets the crash banner across
+//! the middle.
+
+use std::fmt;
+
+use bevy::math::Vec2;
+
+use super::sim::{ObjectKind, Snapshot};
+
+// World area drawn in the playfield: spawns reach ±380, Santa stays in ±320
+const VIEW_MIN: Vec2 = Vec2::new(-400.0, -360.0);
+const VIEW_MAX: Vec2 = Vec2::new(400.0, 380.0);
+const HEAT_BAR: usize = 20;
+
+/// Smallest frame that still fits the HUD.
+pub const MIN_SIZE: (usize, usize) = (60, 12);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextFrame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl TextFrame {
+    /// Draws `snapshot` at `width` × `height` characters (at least [`MIN_SIZE`]).
+    pub fn render(snapshot: &Snapshot, width: usize, height: usize) -> Self {
+        let mut frame = Self {
+            width: width.max(MIN_SIZE.0),
+            height: height.max(MIN_SIZE.1),
+            cells: Vec::new(),
+        };
+        frame.cells = vec![' '; frame.width * frame.height];
+
+        frame.hud(snapshot);
+        for flake in &snapshot.snow {
+            frame.plot(*flake, '.');
+        }
+        for object in &snapshot.objects {
+            let glyph = match object.kind {
+                ObjectKind::Present => '#',
+                ObjectKind::GoldenPresent => '$',
+                ObjectKind::Fan => '@',
+                ObjectKind::Bit => '%',
+                ObjectKind::Coal => 'c',
+                ObjectKind::Elf => 'e',
+                ObjectKind::Boss => 'G',
+            };
+            frame.plot(object.position, glyph);
+        }
+
+        // 🎅 Drawn last so nothing hides him
+        if let Some((column, row)) = frame.cell(snapshot.santa) {
+            for (offset, glyph) in ['<', 'S', '>'].into_iter().enumerate() {
+                let column = (column + offset).saturating_sub(1);
+                frame.put(column, row, glyph);
+            }
+        }
+
+        if snapshot.crashed {
+            let (title, detail) = snapshot
+                .crash_cause
+                .map_or(("SYSTEM FAILURE", ""), |cause| {
+                    (cause.title(), cause.detail())
+                });
+            let middle = frame.height / 2;
+            let width = frame.width;
+            frame.cells[(middle - 1) * width..(middle + 2) * width].fill(' ');
+            frame.center(middle - 1, title);
+            frame.center(middle, detail);
+            frame.center(middle + 1, "PRESS R TO REBOOT");
+        }
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// One string per row, trailing spaces included.
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.cells
+            .chunks(self.width)
+            .map(|row| row.iter().collect())
+    }
+
+    // SCORE 1234  HEAT [#######.............] 35%  LVL 1.4  OVERCLOCK  DASH
+    fn hud(&mut self, snapshot: &Snapshot) {
+        let filled = ((snapshot.heat / 100.0).clamp(0.0, 1.0) * HEAT_BAR as f32).round() as usize;
+        let bar: String = (0..HEAT_BAR)
+            .map(|i| if i < filled { '#' } else { '.' })
+            .collect();
+
+        let mut hud = format!(
+            "SCORE {}  HEAT [{bar}] {}%  LVL {:.1}",
+            snapshot.score, snapshot.heat as i32, snapshot.difficulty
+        );
+        if snapshot.overclocking {
+            hud.push_str("  OVERCLOCK");
+        }
+        if snapshot.dash_ready {
+            hud.push_str("  DASH");
+        }
+        for (column, glyph) in hud.chars().enumerate() {
+            self.put(column, 0, glyph);
+        }
+    }
+
+    // Playfield cell for a world position, if it's in view
+    fn cell(&self, position: Vec2) -> Option<(usize, usize)> {
+        let t = (position - VIEW_MIN) / (VIEW_MAX - VIEW_MIN);
+        if !(0.0..1.0).contains(&t.x) || !(0.0..1.0).contains(&t.y) {
+            return None;
+        }
+        // Row 0 is the HUD; world y points up, rows point down
+        let rows = self.height - 1;
+        let column = (t.x * self.width as f32) as usize;
+        let row = 1 + ((1.0 - t.y) * rows as f32).min(rows as f32 - 1.0) as usize;
+        Some((column, row))
+    }
+
+    fn plot(&mut self, position: Vec2, glyph: char) {
+        if let Some((column, row)) = self.cell(position) {
+            self.put(column, row, glyph);
+        }
+    }
+
+    fn put(&mut self, column: usize, row: usize, glyph: char) {
+        if column < self.width && row < self.height {
+            self.cells[row * self.width + column] = glyph;
+        }
+    }
+
+    fn center(&mut self, row: usize, text: &str) {
+        let start = self.width.saturating_sub(text.chars().count()) / 2;
+        for (offset, glyph) in text.chars().enumerate() {
+            self.put(start + offset, row, glyph);
+        }
+    }
+}
+
+impl fmt::Display for TextFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
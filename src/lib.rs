@@ -0,0 +1,4 @@
+pub mod analytics;
+pub mod collision;
+pub mod game;
+pub mod storage;
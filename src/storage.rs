@@ -0,0 +1,54 @@
+//! Small key/value save storage for profile data (achievements, lifetime stats).
+//!
+//! Native builds write one file per key under `saves/`; the web build uses the
+//! browser's `localStorage`. Values are plain strings so callers pick the format.
+
+#[cfg(not(target_arch = "wasm32"))]
+const SAVE_DIR: &str = "saves";
+
+#[cfg(target_arch = "wasm32")]
+const KEY_PREFIX: &str = "christmas_overclock.";
+
+/// The stored value for `key`, or `None` if nothing was saved yet.
+pub fn load(key: &str) -> Option<String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::fs::read_to_string(path(key)).ok()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        local_storage()?
+            .get_item(&format!("{KEY_PREFIX}{key}"))
+            .ok()
+            .flatten()
+    }
+}
+
+/// Overwrites `key`. Failures are returned so callers can log them; a lost save
+/// should never stop the game.
+pub fn save(key: &str, value: &str) -> Result<(), String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::fs::create_dir_all(SAVE_DIR).map_err(|err| err.to_string())?;
+        std::fs::write(path(key), value).map_err(|err| err.to_string())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        local_storage()
+            .ok_or_else(|| "localStorage unavailable".to_string())?
+            .set_item(&format!("{KEY_PREFIX}{key}"), value)
+            .map_err(|err| format!("{err:?}"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn path(key: &str) -> std::path::PathBuf {
+    std::path::Path::new(SAVE_DIR).join(format!("{key}.ron"))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}